@@ -0,0 +1,85 @@
+// Benchmarks for every implemented day, with a group per day timing the parse and each part
+// on their own, eg "2023/day12/part2". Days run on their real input when it's there, or on
+// their first example otherwise, so the benches work on a fresh clone.
+//
+// cargo bench                        # every day
+// cargo bench -- 2023/day12          # one day
+// cargo bench -- part2 --save-baseline before
+use aoc_2023_jmm::{solvers, Part, Solver};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::fs::read_to_string;
+use std::time::{Duration, Instant};
+
+// Days slower than this take fewer samples, so the whole suite still finishes in a few minutes
+const SLOW: Duration = Duration::from_millis(50);
+
+// The input to benchmark a day on, where it came from, and the solver to run on it, which for
+// an example can be set up differently to the day's own
+fn bench_input(solver: &'static dyn Solver) -> Option<(String, String, &'static dyn Solver)> {
+    match read_to_string(solver.input_path()) {
+        Ok(input) => Some((input, solver.input_path().display().to_string(), solver)),
+        Err(_) => {
+            let example = solver.examples().first()?;
+            Some((
+                example.input.to_string(),
+                "its first example".to_string(),
+                example.solver.unwrap_or(solver),
+            ))
+        }
+    }
+}
+
+fn bench_day(c: &mut Criterion, solver: &'static dyn Solver) {
+    let parts: Vec<Part> = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|&part| solver.is_implemented(part))
+        .collect();
+    if parts.is_empty() {
+        return;
+    }
+    let Some((input, source, solver)) = bench_input(solver) else {
+        eprintln!("{}: no input or example, skipping", solver.name());
+        return;
+    };
+
+    let mut group = c.benchmark_group(solver.name());
+    // Errors are reported below, part by part, since an example can be meant for just one
+    let time = Instant::now();
+    let _ = solver.run(&input, None);
+    if time.elapsed() > SLOW {
+        group.sample_size(10);
+    }
+
+    group.bench_function("parse", |b| {
+        b.iter(|| solver.with_parsed(black_box(&input), &mut |_| {}))
+    });
+    let parsed = solver.with_parsed(&input, &mut |solve| {
+        for &part in &parts {
+            let name = if part == Part::One { "part1" } else { "part2" };
+            if let Err(err) = solve(part) {
+                eprintln!(
+                    "{} {} on {}, skipping: {}",
+                    solver.name(),
+                    name,
+                    source,
+                    err
+                );
+                continue;
+            }
+            group.bench_function(name, |b| b.iter(|| solve(black_box(part))));
+        }
+    });
+    if let Err(err) = parsed {
+        eprintln!("{} on {}, skipping: {}", solver.name(), source, err);
+    }
+    group.finish();
+}
+
+fn bench_days(c: &mut Criterion) {
+    for solver in solvers() {
+        bench_day(c, solver);
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
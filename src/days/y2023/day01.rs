@@ -1,39 +1,88 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use rayon::prelude::*;
-use regex::Regex;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day01.txt").expect("Day 1 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1 = concatenate_and_sum(&lines, false);
-    let sol2 = concatenate_and_sum(&lines, true);
+pub struct Day01;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day01 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Trebuchet?!"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(concatenate_and_sum(lines, false)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(concatenate_and_sum(lines, true)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
-fn concatenate_and_sum(input: &[&str], parse_digit_words: bool) -> u32 {
+register!(Day01);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[
+    Example::new(
+        r#"1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet"#,
+        Some("142"),
+        None,
+    ),
+    Example::new(
+        r#"two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen"#,
+        None,
+        Some("281"),
+    ),
+];
+
+fn concatenate_and_sum(input: &[&str], parse_digit_words: bool) -> Result<u32> {
     input
         .par_iter()
-        .map(|line| {
-            let (first_digit, last_digit) = find_first_last_digit(line, parse_digit_words);
-            format!("{:?}{:?}", first_digit, last_digit)
-                .parse::<u32>()
-                .unwrap()
+        .enumerate()
+        .map(|(i, line)| {
+            let (first_digit, last_digit) = find_first_last_digit(line, parse_digit_words)
+                .ok_or_else(|| Error::new("line has no digits").at_line(i + 1))?;
+            Ok(format!("{:?}{:?}", first_digit, last_digit).parse::<u32>()?)
         })
         .sum()
 }
 
-const DIGIT_WORDS: [&'static str; 10] = [
+const DIGIT_WORDS: [&str; 10] = [
     "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
 ];
 
-fn find_first_last_digit(line: &str, parse_digit_words: bool) -> (usize, usize) {
+fn find_first_last_digit(line: &str, parse_digit_words: bool) -> Option<(usize, usize)> {
     // Find the indices of the first and last numerical digits in the line.
-    let first_digit_index = line.find(|c: char| c.is_digit(10));
-    let last_digit_index = line.rfind(|c: char| c.is_digit(10));
+    let first_digit_index = line.find(|c: char| c.is_ascii_digit());
+    let last_digit_index = line.rfind(|c: char| c.is_ascii_digit());
 
     let mut first_digit: Option<usize> = None;
     let mut last_digit: Option<usize> = None;
@@ -76,17 +125,17 @@ fn find_first_last_digit(line: &str, parse_digit_words: bool) -> (usize, usize)
 
     // If we didn't find any digit words before the first digit index
     // (maybe because we're not looking for digit words), then use the first digit index.
-    if first_digit_index.is_some() && first_digit.is_none() {
-        let digit_char = line.chars().nth(first_digit_index.unwrap()).unwrap();
+    if let (Some(index), None) = (first_digit_index, first_digit) {
+        let digit_char = line.chars().nth(index).unwrap();
         first_digit = Some(digit_char.to_digit(10).unwrap() as usize);
     }
 
-    if last_digit_index.is_some() && last_digit.is_none() {
-        let digit_char = line.chars().nth(last_digit_index.unwrap()).unwrap();
+    if let (Some(index), None) = (last_digit_index, last_digit) {
+        let digit_char = line.chars().nth(index).unwrap();
         last_digit = Some(digit_char.to_digit(10).unwrap() as usize);
     }
 
-    (first_digit.unwrap(), last_digit.unwrap())
+    first_digit.zip(last_digit)
 }
 
 #[cfg(test)]
@@ -96,23 +145,24 @@ mod tests {
     #[test]
     fn test_find_first_last_digit() {
         let input = "two1nine";
-        assert_eq!(find_first_last_digit(input, false), (1, 1));
-        assert_eq!(find_first_last_digit(input, true), (2, 9));
+        assert_eq!(find_first_last_digit(input, false), Some((1, 1)));
+        assert_eq!(find_first_last_digit(input, true), Some((2, 9)));
 
         let input = "oneight";
-        assert_eq!(find_first_last_digit(input, true), (1, 8));
+        assert_eq!(find_first_last_digit(input, true), Some((1, 8)));
 
         let input = "34onefive98";
-        assert_eq!(find_first_last_digit(input, false), (3, 8));
-        assert_eq!(find_first_last_digit(input, true), (3, 8));
+        assert_eq!(find_first_last_digit(input, false), Some((3, 8)));
+        assert_eq!(find_first_last_digit(input, true), Some((3, 8)));
 
         let input = "two";
-        assert_eq!(find_first_last_digit(input, true), (2, 2));
+        assert_eq!(find_first_last_digit(input, true), Some((2, 2)));
+        assert_eq!(find_first_last_digit(input, false), None);
     }
 
     #[test]
     fn test_concatenate_and_sum() {
         let input = ["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"];
-        assert_eq!(concatenate_and_sum(&input, false), 142);
+        assert_eq!(concatenate_and_sum(&input, false), Ok(142));
     }
 }
@@ -1,19 +1,57 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use rayon::prelude::*;
 use regex::Regex;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day02.txt").expect("Day 2 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1 = sum_of_valid_games(&lines);
-    let sol2 = sum_of_games_power(&lines);
+pub struct Day02;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day02 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        2
+    }
+
+    fn title(&self) -> &'static str {
+        "Cube Conundrum"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_valid_games(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_games_power(lines)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day02);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green",
+    Some("8"),
+    Some("2286"),
+)];
+
 const MAX_RED_CUBES: u32 = 12;
 const MAX_GREEN_CUBES: u32 = 13;
 const MAX_BLUE_CUBES: u32 = 14;
@@ -26,19 +64,24 @@ const MAX_BLUE_CUBES: u32 = 14;
 // A valid game only has valid plays.
 // The "id" of a game is its number.
 // Get the sum of the ids of all valid games.
-fn sum_of_valid_games(input: &[&str]) -> u32 {
+fn sum_of_valid_games(input: &[&str]) -> Result<u32> {
     let re = Regex::new(r"Game (\d+): (.*)").unwrap();
 
     input
         .par_iter()
-        .filter_map(|line| {
-            let cap = re.captures(line)?;
-            let id = cap[1].parse::<u32>().ok()?;
+        .enumerate()
+        .map(|(i, line)| {
+            let cap = re
+                .captures(line)
+                .ok_or_else(|| Error::new("expected \"Game <id>: <plays>\"").at_line(i + 1))?;
+            let id = cap[1]
+                .parse::<u32>()
+                .map_err(|err| Error::from(err).at_line(i + 1))?;
             let plays = &cap[2];
-            if plays.split("; ").all(|play| is_play_valid(play)) {
-                Some(id)
+            if plays.split("; ").all(is_play_valid) {
+                Ok(id)
             } else {
-                None
+                Ok(0)
             }
         })
         .sum()
@@ -68,13 +111,16 @@ fn is_play_valid(play: &str) -> bool {
 // The minimum number of cubes needed is the maximum number of cubes for each color.
 // The power of a game is the product of the minimum number of cubes for each game.
 // Get the sum of the powers of all games.
-fn sum_of_games_power(input: &[&str]) -> u32 {
+fn sum_of_games_power(input: &[&str]) -> Result<u32> {
     let re = Regex::new(r"(\d+) (red|green|blue)").unwrap();
 
     input
         .par_iter()
-        .map(|line| {
-            let plays = line.split(": ").nth(1).unwrap_or("");
+        .enumerate()
+        .map(|(i, line)| {
+            let (_, plays) = line
+                .split_once(": ")
+                .ok_or_else(|| Error::new("expected \"Game <id>: <plays>\"").at_line(i + 1))?;
             let (mut max_red, mut max_green, mut max_blue) = (0, 0, 0);
 
             for play in plays.split("; ") {
@@ -93,7 +139,7 @@ fn sum_of_games_power(input: &[&str]) -> u32 {
                 max_blue = max_blue.max(blue_cubes);
             }
 
-            max_red * max_green * max_blue
+            Ok(max_red * max_green * max_blue)
         })
         .sum()
 }
@@ -103,11 +149,7 @@ mod tests {
     use super::*;
 
     fn day02_test_input() -> &'static str {
-        "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n\
-Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
-Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
-Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
-Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
+        EXAMPLES[0].input
     }
 
     #[test]
@@ -115,7 +157,7 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
         let input = day02_test_input();
         // split into lines
         let input: Vec<&str> = input.lines().collect();
-        assert_eq!(sum_of_valid_games(&input), 8);
+        assert_eq!(sum_of_valid_games(&input), Ok(8));
     }
 
     #[test]
@@ -123,6 +165,6 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
         let input = day02_test_input();
         // split into lines
         let input: Vec<&str> = input.lines().collect();
-        assert_eq!(sum_of_games_power(&input), 2286);
+        assert_eq!(sum_of_games_power(&input), Ok(2286));
     }
 }
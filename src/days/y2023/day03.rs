@@ -1,36 +1,73 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::grid::Grid;
+use crate::etc::{Example, Puzzle, Result, Solution};
+use crate::register;
 use rayon::prelude::*;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day03.txt").expect("Day 3 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1 = sum_of_part_numbers(&lines);
-    let sol2 = sum_of_gear_ratios(&lines);
+pub struct Day03;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day03 {
+    type Input<'a> = Schematic;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Gear Ratios"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Schematic {
+            grid: Grid::parse_chars(input)?,
+        })
+    }
+
+    fn part1(&self, schematic: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_part_numbers(schematic)))
+    }
+
+    fn part2(&self, schematic: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_gear_ratios(schematic)))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
-struct Schematic {
-    rows: usize,
-    cols: usize,
-    grid: Vec<Vec<char>>,
+register!(Day03);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#,
+    Some("4361"),
+    Some("467835"),
+)];
+
+pub struct Schematic {
+    grid: Grid<char>,
 }
 
 impl Schematic {
-    fn new(grid: Vec<Vec<char>>) -> Schematic {
-        let rows = grid.len();
-        let cols = grid[0].len();
-        Schematic { rows, cols, grid }
-    }
-
     fn part_numbers(&self) -> Vec<u32> {
-        self.grid
-            .par_iter()
-            .enumerate()
-            .flat_map(|(i, row)| self.process_row(i, row))
+        (0..self.grid.rows())
+            .into_par_iter()
+            .flat_map(|i| self.process_row(i, self.grid.row(i)))
             .collect()
     }
 
@@ -38,9 +75,9 @@ impl Schematic {
         let mut result = Vec::new();
         let mut j = 0;
         while j < row.len() {
-            if row[j].is_digit(10) {
+            if row[j].is_ascii_digit() {
                 let start = j;
-                while j < row.len() && row[j].is_digit(10) {
+                while j < row.len() && row[j].is_ascii_digit() {
                     j += 1;
                 }
                 let end = j;
@@ -62,8 +99,8 @@ impl Schematic {
     }
 
     fn is_valid_part_number(&self, row: usize, start_col: usize, end_col: usize) -> bool {
-        for i in (row.max(1) - 1)..=(row + 1).min(self.rows - 1) {
-            for j in (start_col.max(1) - 1)..=(end_col).min(self.cols - 1) {
+        for i in (row.max(1) - 1)..=(row + 1).min(self.grid.rows() - 1) {
+            for j in (start_col.max(1) - 1)..=(end_col).min(self.grid.cols() - 1) {
                 if self.is_valid_neighbor(row, i, j, start_col, end_col) {
                     return true;
                 }
@@ -83,21 +120,18 @@ impl Schematic {
         (i != row || j < start_col || j >= end_col)
             && self
                 .grid
-                .get(i)
-                .and_then(|r| r.get(j))
-                .map_or(false, |&ch| !ch.is_digit(10) && ch != '.')
+                .get((i, j))
+                .is_some_and(|&ch| !ch.is_ascii_digit() && ch != '.')
     }
 
     fn gear_ratios(&self) -> Vec<u32> {
         let mut ratios = Vec::new();
-        for (i, row) in self.grid.iter().enumerate() {
-            for (j, &ch) in row.iter().enumerate() {
-                if ch == '*' {
-                    let part_numbers = self.find_adjacent_part_numbers(i, j);
-                    // println!("Gear at ({}, {}): {:?}", i, j, part_numbers);
-                    if part_numbers.len() == 2 {
-                        ratios.push(part_numbers[0].0 * part_numbers[1].0); // Multiply the part numbers
-                    }
+        for ((i, j), &ch) in self.grid.cells() {
+            if ch == '*' {
+                let part_numbers = self.find_adjacent_part_numbers(i, j);
+                // println!("Gear at ({}, {}): {:?}", i, j, part_numbers);
+                if part_numbers.len() == 2 {
+                    ratios.push(part_numbers[0].0 * part_numbers[1].0); // Multiply the part numbers
                 }
             }
         }
@@ -109,29 +143,8 @@ impl Schematic {
         let mut checked_positions = std::collections::HashSet::new();
 
         // Check each of the eight directions
-        for &(dy, dx) in [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ]
-        .iter()
-        {
-            let (new_row, new_col) = (row as isize + dy, col as isize + dx);
-            if new_row < 0
-                || new_row >= self.rows as isize
-                || new_col < 0
-                || new_col >= self.cols as isize
-            {
-                continue;
-            }
-
-            let (new_row, new_col) = (new_row as usize, new_col as usize);
-            if !self.grid[new_row][new_col].is_digit(10) {
+        for (new_row, new_col) in self.grid.neighbors8((row, col)) {
+            if !self.grid[(new_row, new_col)].is_ascii_digit() {
                 continue;
             }
 
@@ -151,20 +164,21 @@ impl Schematic {
     }
 
     fn get_horizontal_number_at(&self, row: usize, col: usize) -> Option<(u32, usize)> {
-        if !self.grid[row][col].is_digit(10) {
+        let row = self.grid.row(row);
+        if !row[col].is_ascii_digit() {
             return None;
         }
 
-        let start = self.grid[row][..col]
+        let start = row[..col]
             .iter()
-            .rposition(|&ch| !ch.is_digit(10))
+            .rposition(|&ch| !ch.is_ascii_digit())
             .map_or(0, |p| p + 1);
-        let end = self.grid[row][col..]
+        let end = row[col..]
             .iter()
-            .position(|&ch| !ch.is_digit(10))
-            .map_or(self.cols, |p| col + p);
+            .position(|&ch| !ch.is_ascii_digit())
+            .map_or(row.len(), |p| col + p);
 
-        self.grid[row][start..end]
+        row[start..end]
             .par_iter()
             .collect::<String>()
             .parse::<u32>()
@@ -184,12 +198,7 @@ impl Schematic {
 // Need to find all the numbers in each row.
 // Then we can check if each number is a part number by checking its surroundings.
 // Then we can sum the part numbers.
-fn sum_of_part_numbers(input: &[&str]) -> u32 {
-    let grid = input
-        .par_iter()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-    let schematic = Schematic::new(grid);
+fn sum_of_part_numbers(schematic: &Schematic) -> u32 {
     let part_numbers = schematic.part_numbers();
     part_numbers.par_iter().sum::<u32>()
 }
@@ -197,12 +206,7 @@ fn sum_of_part_numbers(input: &[&str]) -> u32 {
 // This time, a gear is an asterisk symbol "*" which is next to exactly two part numbers.
 // Its gear ratio is the product of the two part numbers.
 // Find the sum of all gear ratios.
-fn sum_of_gear_ratios(input: &[&str]) -> u32 {
-    let grid = input
-        .par_iter()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-    let schematic = Schematic::new(grid);
+fn sum_of_gear_ratios(schematic: &Schematic) -> u32 {
     let gear_ratios = schematic.gear_ratios();
     gear_ratios.par_iter().sum::<u32>()
 }
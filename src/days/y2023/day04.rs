@@ -0,0 +1,144 @@
+use crate::etc::error::{parse_lines, parse_number};
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+use rayon::prelude::*;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day04;
+
+impl Puzzle for Day04 {
+    // The number of matches on each card is all either part needs
+    type Input<'a> = Vec<u32>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Scratchcards"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let lines: Vec<&str> = input.lines().collect();
+        parse_lines(&lines, count_matches)
+    }
+
+    fn part1(&self, cards: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(scratchcard_total_points(cards)))
+    }
+
+    fn part2(&self, cards: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(total_scratchcards(cards)))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day04);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n\
+        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n\
+        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\n\
+        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\n\
+        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\n\
+        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+    Some("13"),
+    Some("30"),
+)];
+
+// Each line has two list of numbers separated by a pipe.
+// The first list is the winning numbers.
+// The second list is the player's numbers.
+// The player gets points for each number in the player's list that is also in the winning list equal to 2^(matches-1).
+// The player gets 0 points if there are no matches, 1 point if there is 1 match, 2 points if there are 2 matches, 4 points if there are 3 matches, etc.
+// The player's total points is the sum of the points for each line.
+fn scratchcard_total_points(cards: &[u32]) -> u32 {
+    let mut total_points = 0;
+    for &matches in cards {
+        total_points += if matches == 0 {
+            0
+        } else {
+            2u32.pow(matches - 1)
+        };
+    }
+    total_points
+}
+
+fn count_matches(line: &str) -> Result<u32> {
+    let (_, numbers_part) = line
+        .split_once(':')
+        .ok_or_else(|| Error::new("expected \"Card <id>: <numbers>\""))?;
+    let (winning_part, player_part) = numbers_part
+        .split_once('|')
+        .ok_or_else(|| Error::new("expected winning and player numbers separated by |"))?;
+    let winning_numbers = winning_part
+        .split_whitespace()
+        .map(parse_number::<u32>)
+        .collect::<Result<Vec<u32>>>()?;
+    let player_numbers = player_part
+        .split_whitespace()
+        .map(parse_number::<u32>)
+        .collect::<Result<Vec<u32>>>()?;
+    // The number of matches is the number of player numbers that are also in the winning numbers.
+    Ok(player_numbers
+        .par_iter()
+        .filter(|&player_number| winning_numbers.contains(player_number))
+        .count() as u32)
+}
+
+// There are no such things as points. Instead, scratchcards cause you to win more scratchcards.
+// Specifically, you win copies of the scratchcards below the winning card equal to the number of its winning numbers.
+fn total_scratchcards(cards: &[u32]) -> u32 {
+    let mut queue: std::collections::VecDeque<_> = cards.par_iter().enumerate().collect();
+    let mut total_cards = cards.len() as u32;
+
+    while let Some((index, &matches)) = queue.pop_front() {
+        // println!("Current card has {} matches", matches);
+        for i in 0..matches {
+            let next_index = index + i as usize + 1;
+            if let Some(next_matches) = cards.get(next_index) {
+                queue.push_back((next_index, next_matches));
+                total_cards += 1;
+            }
+        }
+    }
+
+    total_cards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day04_test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    #[test]
+    fn test_scratchcard_total_points() {
+        let input = day04_test_input();
+        // split into lines
+        let input: Vec<&str> = input.lines().collect();
+        let cards = parse_lines(&input, count_matches).unwrap();
+        assert_eq!(scratchcard_total_points(&cards), 13);
+        assert_eq!(total_scratchcards(&cards), 30);
+    }
+
+    #[test]
+    fn test_count_matches_invalid() {
+        let err = parse_lines(
+            &["Card 1: 41 48 | 83 86", "Card 2: 13 x2 | 61"],
+            count_matches,
+        );
+        assert_eq!(err.unwrap_err().line, Some(2));
+    }
+}
@@ -1,26 +1,92 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::error::parse_number;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use std::{
     cmp::Ordering,
     fmt::{Display, Formatter},
-    fs::read_to_string,
 };
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    // Your solution here...
-    let input = read_to_string("input/day05.txt").expect("Day 5 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1 = lowest_location_number(&lines, false);
-    let sol2 = lowest_location_number(&lines, true);
+pub struct Day05;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day05 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        5
+    }
+
+    fn title(&self) -> &'static str {
+        "If You Give A Seed A Fertilizer"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(lowest_location_number(lines, false)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(lowest_location_number(lines, true)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day05);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4",
+    Some("35"),
+    Some("46"),
+)];
+
 // A Map is essentially a piecewise function from u64 to u64.
 // All we need to store is the start of each range, and the shift for that range. The end of a range is the start of the next range.
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     mappings: Vec<RangeShift>,
 }
 
@@ -33,7 +99,7 @@ struct RangeShift {
 impl Display for Map {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // A mapping looks like [start, end) -> [start + shift, end + shift)
-        write!(f, "Mappings:\n")?;
+        writeln!(f, "Mappings:")?;
         let mut mappings = self.mappings.iter();
         let mut current_mapping = mappings.next();
         while let Some(mapping) = current_mapping {
@@ -60,8 +126,15 @@ impl Display for Map {
     }
 }
 
+impl Default for Map {
+    fn default() -> Self {
+        Map::new()
+    }
+}
+
 impl Map {
-    fn new() -> Self {
+    // An empty Map sends every number to itself
+    pub fn new() -> Self {
         Map {
             mappings: vec![RangeShift {
                 range_start: 0,
@@ -70,7 +143,7 @@ impl Map {
         }
     }
 
-    fn add_range_shift(
+    pub fn add_range_shift(
         &mut self,
         source_range_start: u64,
         destination_range_start: u64,
@@ -124,7 +197,7 @@ impl Map {
         }
     }
 
-    fn get_destination(&self, source: u64) -> u64 {
+    pub fn get_destination(&self, source: u64) -> u64 {
         // We can find the destination by finding the range shift that contains the source, and adding the shift to the source. The way we're storing range shifts, the range shift that contains the source is the one with the largest range start that is less than or equal to the source.
         // All numbers u64 are in some range shift because the last range shift will be some n with shift 0, and all numbers greater than or equal to n will map to themselves.
         let pos = self.mappings.binary_search_by(|probe| {
@@ -142,7 +215,7 @@ impl Map {
         }
     }
 
-    fn transform_range(&self, range_start: u64, range_end: u64) -> Vec<(u64, u64)> {
+    pub fn transform_range(&self, range_start: u64, range_end: u64) -> Vec<(u64, u64)> {
         // find the largest range shift that is less than or equal to the range start
         // find the largest range shift that is less than or equal to the range end
         // this may encompass one or multiple range shifts
@@ -161,7 +234,12 @@ impl Map {
         };
 
         // This loop splits the range into multiple ranges, each of which is transformed by a single mapping.
-        for (i, mapping) in self.mappings.iter().enumerate().skip(start_index - 1) {
+        for (i, mapping) in self
+            .mappings
+            .iter()
+            .enumerate()
+            .skip(start_index.saturating_sub(1))
+        {
             // We've processed all relevant mappings
             if mapping.range_start > range_end {
                 break;
@@ -195,32 +273,26 @@ impl Map {
     }
 }
 
-fn lowest_location_number(input: &[&str], range_based: bool) -> u64 {
-    let maps = input[1..]
-        .split(|line| line.is_empty())
-        .filter(|lines| !lines.is_empty()) // Filter out empty line groups
-        .map(|lines| {
-            let mut map = Map::new();
-            for line in lines.iter().skip(1) {
-                let (destination_range_start, source_range_start, range_length) =
-                    parse_map_line(line);
-                map.add_range_shift(source_range_start, destination_range_start, range_length);
-            }
-            map
-        })
-        .collect::<Vec<Map>>();
-
-    if range_based {
-        let ranges = input[0]
-            .split_whitespace()
-            .skip(1)
-            .collect::<Vec<&str>>()
+fn lowest_location_number(input: &[&str], range_based: bool) -> Result<u64> {
+    let seeds_line = input
+        .first()
+        .and_then(|line| line.strip_prefix("seeds:"))
+        .ok_or_else(|| Error::new("expected \"seeds: <numbers>\"").at_line(1))?;
+    let seed_numbers = seeds_line
+        .split_whitespace()
+        .map(parse_number::<u64>)
+        .collect::<Result<Vec<u64>>>()
+        .map_err(|err| err.at_line(1))?;
+    let maps = parse_maps(input)?;
+
+    let lowest = if range_based {
+        if seed_numbers.len() % 2 != 0 {
+            return Err(Error::new("seed ranges should come in start/length pairs").at_line(1));
+        }
+        let ranges = seed_numbers
             .chunks(2)
-            .map(|chunk| {
-                let start = chunk[0].parse::<u64>().unwrap();
-                let length = chunk[1].parse::<u64>().unwrap();
-                (start, start + length - 1)
-            })
+            .filter(|chunk| chunk[1] > 0)
+            .map(|chunk| (chunk[0], chunk[0] + chunk[1] - 1))
             .collect::<Vec<(u64, u64)>>();
 
         let mut current_ranges = ranges;
@@ -245,15 +317,8 @@ fn lowest_location_number(input: &[&str], range_based: bool) -> u64 {
             .into_iter()
             .map(|range| range.0.min(range.1))
             .min()
-            .unwrap()
     } else {
-        let seeds = input[0]
-            .split_whitespace()
-            .skip(1)
-            .filter_map(|s| s.parse::<u64>().ok())
-            .collect::<Vec<u64>>();
-
-        let mut current_seeds = seeds;
+        let mut current_seeds = seed_numbers;
         for map in &maps {
             let mut next_seeds = Vec::new();
             for seed in &current_seeds {
@@ -265,16 +330,45 @@ fn lowest_location_number(input: &[&str], range_based: bool) -> u64 {
             current_seeds = next_seeds;
         }
 
-        *current_seeds.iter().min().unwrap()
+        current_seeds.into_iter().min()
+    };
+
+    lowest.ok_or_else(|| Error::new("no seeds given").at_line(1))
+}
+
+// After the seeds line, the input is a list of maps, each a "<name> map:" header followed by range lines.
+pub fn parse_maps(input: &[&str]) -> Result<Vec<Map>> {
+    let mut maps = Vec::new();
+    for (i, line) in input.iter().enumerate().skip(1) {
+        if line.is_empty() {
+            continue;
+        }
+        if line.ends_with("map:") {
+            maps.push(Map::new());
+            continue;
+        }
+        let map = maps
+            .last_mut()
+            .ok_or_else(|| Error::new("expected a map header before any ranges").at_line(i + 1))?;
+        let (destination_range_start, source_range_start, range_length) =
+            parse_map_line(line).map_err(|err| err.at_line(i + 1))?;
+        map.add_range_shift(source_range_start, destination_range_start, range_length);
     }
+    Ok(maps)
 }
 
-fn parse_map_line(line: &str) -> (u64, u64, u64) {
-    let parts: Vec<u64> = line
+fn parse_map_line(line: &str) -> Result<(u64, u64, u64)> {
+    let parts = line
         .split_whitespace()
-        .filter_map(|s| s.parse::<u64>().ok())
-        .collect();
-    (parts[0], parts[1], parts[2])
+        .map(parse_number::<u64>)
+        .collect::<Result<Vec<u64>>>()?;
+    match parts[..] {
+        [destination, source, length] => Ok((destination, source, length)),
+        _ => Err(Error::new(format!(
+            "expected 3 numbers in a map line, found {}",
+            parts.len()
+        ))),
+    }
 }
 
 #[cfg(test)]
@@ -282,39 +376,7 @@ mod tests {
     use super::*;
 
     fn day05_test_input() -> &'static str {
-        "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4"
+        EXAMPLES[0].input
     }
 
     #[test]
@@ -322,7 +384,7 @@ humidity-to-location map:
         let input = day05_test_input();
         // split into lines
         let input: Vec<&str> = input.lines().collect();
-        assert_eq!(lowest_location_number(&input, false), 35);
+        assert_eq!(lowest_location_number(&input, false), Ok(35));
     }
 
     #[test]
@@ -330,6 +392,16 @@ humidity-to-location map:
         let input = day05_test_input();
         // split into lines
         let input: Vec<&str> = input.lines().collect();
-        assert_eq!(lowest_location_number(&input, true), 46);
+        assert_eq!(lowest_location_number(&input, true), Ok(46));
+    }
+
+    #[test]
+    fn test_parse_map_line_invalid() {
+        assert_eq!(parse_map_line("50 98 2"), Ok((50, 98, 2)));
+        assert!(parse_map_line("50 98").is_err());
+        let input = day05_test_input().replace("37 52 2", "37 5x 2");
+        let input: Vec<&str> = input.lines().collect();
+        let err = lowest_location_number(&input, false).unwrap_err();
+        assert_eq!(err.line, Some(9));
     }
 }
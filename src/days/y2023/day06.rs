@@ -0,0 +1,149 @@
+use crate::etc::error::parse_number;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day06;
+
+impl Puzzle for Day06 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        6
+    }
+
+    fn title(&self) -> &'static str {
+        "Wait For It"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(product_of_ways_to_beat_records(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(ways_to_beat_record(lines)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day06);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    "Time:      7  15   30
+        Distance:  9  40  200",
+    Some("288"),
+    Some("71503"),
+)];
+
+// The input is two lines of text, each with a list of numbers, listing a series of records for a toy boat race.
+// The first line is how long each race lasted (milliseconds), and the second line is the record distance (millimeters) for that race.
+// The records are space-separated in the same order, so the first record in the first line is the time for the first distance record in the second line.
+// A toy boat has two actions, charge and release. A charge action takes 1 millisecond, and increases the boat's speed by 1 millimeter per millisecond. A boat cannot move until the charge actions are complete.
+// If a race last for 5 milliseconds, then there are 6 possibilities, from 0 to 5 charges. 0 charges means the boat never moved, and 5 charges means the boat moved 5 millimeters per millisecond, but that's the entire duration of the race, so the boat cannot move. With 3 charges, then the boat does not move for the first 3 milliseconds, and then moves 3 millimeters per millisecond for the remaining 2 milliseconds for a total of 6 millimeters.
+// We want to find the number of ways to beat the record
+fn product_of_ways_to_beat_records(input: &[&str]) -> Result<u64> {
+    let times: Vec<u64> = record_line(input, 0, "Time:")?
+        .iter()
+        .map(|x| parse_number(x))
+        .collect::<Result<_>>()
+        .map_err(|err| err.at_line(1))?;
+    let distances: Vec<u64> = record_line(input, 1, "Distance:")?
+        .iter()
+        .map(|x| parse_number(x))
+        .collect::<Result<_>>()
+        .map_err(|err| err.at_line(2))?;
+    if times.len() != distances.len() {
+        return Err(Error::new(format!(
+            "expected {} distances to match the times, found {}",
+            times.len(),
+            distances.len()
+        ))
+        .at_line(2));
+    }
+
+    let mut product = 1;
+    for (time, distance) in times.iter().zip(distances.iter()) {
+        let ways_to_beat_record = calculate_ways_to_beat_record(*time, *distance);
+        product *= ways_to_beat_record;
+    }
+
+    Ok(product)
+}
+
+// Each line is a label followed by numbers, eg "Time:      7  15   30"
+fn record_line<'a>(input: &[&'a str], index: usize, label: &str) -> Result<Vec<&'a str>> {
+    input
+        .get(index)
+        .and_then(|line| line.trim_start().strip_prefix(label))
+        .map(|numbers| numbers.split_whitespace().collect())
+        .ok_or_else(|| Error::new(format!("expected \"{} <numbers>\"", label)).at_line(index + 1))
+}
+
+fn calculate_ways_to_beat_record(time: u64, distance: u64) -> u64 {
+    let optimal_charge = time / 2;
+    let mut ways_to_beat_record = 0;
+
+    for charge in 0..=optimal_charge {
+        let distance_covered = charge * (time - charge);
+        if distance_covered > distance {
+            // Count this charge and its mirror charge if it's different
+            ways_to_beat_record += if charge == optimal_charge && time.is_multiple_of(2) {
+                1
+            } else {
+                2
+            };
+        }
+    }
+
+    ways_to_beat_record
+}
+
+// In the second part, the input is the same, but we parse it differently just by concatening the numbers in each line into a single number.
+// So instead of times of 7, 15, 30 and distances of 9, 40, 200, we have a time of 71530 and a distance of 940200 which we do the same calculation on (might need to optimize the calculation).
+fn ways_to_beat_record(input: &[&str]) -> Result<u64> {
+    // The numbers are really one number with bad kerning, so the spaces are ignored
+    let time: u64 =
+        parse_number(&record_line(input, 0, "Time:")?.concat()).map_err(|err| err.at_line(1))?;
+    let distance: u64 = parse_number(&record_line(input, 1, "Distance:")?.concat())
+        .map_err(|err| err.at_line(2))?;
+
+    Ok(calculate_ways_to_beat_record(time, distance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day06_test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    #[test]
+    fn test_product_of_ways_to_beat_record() {
+        let input = day06_test_input();
+        // split into lines
+        let input: Vec<&str> = input.lines().collect();
+        assert_eq!(product_of_ways_to_beat_records(&input), Ok(288));
+    }
+
+    #[test]
+    fn test_ways_to_beat_record() {
+        let input = day06_test_input();
+        // split into lines
+        let input: Vec<&str> = input.lines().collect();
+        assert_eq!(ways_to_beat_record(&input), Ok(71503));
+    }
+}
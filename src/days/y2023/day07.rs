@@ -1,29 +1,69 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::error::parse_number;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use itertools::Itertools;
 use rayon::prelude::*;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day07.txt").expect("Day 7 input file should be present.");
-    let input: Vec<&str> = input.lines().collect();
-    let sol1: u64 = poker_total_winnings(&input, false);
-    let sol2: u64 = poker_total_winnings(&input, true);
+pub struct Day07;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day07 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        7
+    }
+
+    fn title(&self) -> &'static str {
+        "Camel Cards"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(poker_total_winnings(lines, false)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(poker_total_winnings(lines, true)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day07);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    "32T3K 765\n\
+        T55J5 684\n\
+        KK677 28\n\
+        KTJJT 220\n\
+        QQQJA 483",
+    Some("6440"),
+    Some("5905"),
+)];
+
 // The input is a list of hands of five cards and their corresponding bids
 // Hands are ordered by type, and then if type is the same, compare first card, then second card, etc.
 // We rank each hand, weakest as 1, then 2, etc.
 // The total winnings is equal to the sum of the bid amounts for each hand multiplied by the rank of the hand
 // In the second part, J instead of being a jack is a joker, which can be any card for the purposes of determining hand type but is always treated as less than any other card
-fn poker_total_winnings(input: &[&str], joker: bool) -> u64 {
+fn poker_total_winnings(input: &[&str], joker: bool) -> Result<u64> {
     let mut hands_with_bids = input
         .par_iter()
-        .map(|line| parse_hand_and_bid(line, joker))
-        .collect::<Vec<_>>();
+        .enumerate()
+        .map(|(i, line)| parse_hand_and_bid(line, joker).map_err(|err| err.at_line(i + 1)))
+        .collect::<Result<Vec<_>>>()?;
 
     hands_with_bids.sort_by(|(a_hand, _), (b_hand, _)| compare_hands(a_hand, b_hand));
 
@@ -32,33 +72,43 @@ fn poker_total_winnings(input: &[&str], joker: bool) -> u64 {
     //     println!("{}: {:?} {}", rank + 1, hand, bid);
     // }
 
-    hands_with_bids
+    Ok(hands_with_bids
         .par_iter()
         .enumerate()
         .map(|(rank, (_hand, bid))| (rank as u64 + 1) * bid)
-        .sum()
+        .sum())
 }
 
-fn parse_hand_and_bid(input: &str, joker: bool) -> (Hand, u64) {
-    let (hand_str, bid_str) = input.split_at(5);
+fn parse_hand_and_bid(input: &str, joker: bool) -> Result<(Hand, u64)> {
+    let (hand_str, bid_str) = input
+        .split_once(' ')
+        .ok_or_else(|| Error::new("expected \"<hand> <bid>\""))?;
     let hand = {
         let cards = hand_str
             .chars()
-            .map(|c| match c {
-                'A' => CardValue::Ace,
-                'K' => CardValue::King,
-                'Q' => CardValue::Queen,
+            .enumerate()
+            .map(|(i, c)| match c {
+                'A' => Ok(CardValue::Ace),
+                'K' => Ok(CardValue::King),
+                'Q' => Ok(CardValue::Queen),
                 'J' => {
                     if joker {
-                        CardValue::Joker
+                        Ok(CardValue::Joker)
                     } else {
-                        CardValue::Jack
+                        Ok(CardValue::Jack)
                     }
                 }
-                'T' => CardValue::Ten,
-                n => CardValue::Number(n.to_digit(10).unwrap() as u8),
+                'T' => Ok(CardValue::Ten),
+                '2'..='9' => Ok(CardValue::Number(c as u8 - b'0')),
+                _ => Err(Error::new(format!("invalid card {:?}", c)).at_column(i + 1)),
             })
-            .collect::<Vec<_>>();
+            .collect::<Result<Vec<_>>>()?;
+        if cards.len() != 5 {
+            return Err(Error::new(format!(
+                "expected 5 cards, found {}",
+                cards.len()
+            )));
+        }
 
         let hand_type = if joker {
             determine_hand_type_with_joker(&cards)
@@ -71,26 +121,25 @@ fn parse_hand_and_bid(input: &str, joker: bool) -> (Hand, u64) {
             hand_type,
         }
     };
-    let bid = bid_str.trim().parse::<u64>().unwrap();
+    let bid = parse_number::<u64>(bid_str)?;
 
-    (hand, bid)
+    Ok((hand, bid))
 }
 
 fn determine_hand_type_with_joker(cards: &[CardValue]) -> HandType {
-    let card_counts = cards.into_iter().counts();
+    let card_counts = cards.iter().counts();
     let joker_count = card_counts.get(&CardValue::Joker).unwrap_or(&0);
 
     if joker_count == &cards.len() {
         return HandType::FiveOfAKind;
     }
 
-    let most_common_card: CardValue = card_counts
+    let most_common_card: CardValue = *card_counts
         .iter()
         .filter(|(&card, _)| card != &CardValue::Joker)
         .max_by_key(|&(_, count)| count)
         .map(|(&card, _)| card)
-        .unwrap_or(&CardValue::Ace)
-        .clone();
+        .unwrap_or(&CardValue::Ace);
 
     let cards_with_jokers_replaced: Vec<CardValue> = cards
         .iter()
@@ -192,11 +241,7 @@ mod tests {
     use super::*;
 
     fn day07_test_input() -> &'static str {
-        "32T3K 765\n\
-        T55J5 684\n\
-        KK677 28\n\
-        KTJJT 220\n\
-        QQQJA 483"
+        EXAMPLES[0].input
     }
 
     #[test]
@@ -204,7 +249,7 @@ mod tests {
         let input = day07_test_input();
         // split into lines
         let input: Vec<&str> = input.lines().collect();
-        assert_eq!(poker_total_winnings(&input, false), 6440);
+        assert_eq!(poker_total_winnings(&input, false), Ok(6440));
     }
 
     #[test]
@@ -212,6 +257,6 @@ mod tests {
         let input = day07_test_input();
         // split into lines
         let input: Vec<&str> = input.lines().collect();
-        assert_eq!(poker_total_winnings(&input, true), 5905);
+        assert_eq!(poker_total_winnings(&input, true), Ok(5905));
     }
 }
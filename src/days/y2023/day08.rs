@@ -0,0 +1,282 @@
+use crate::etc::math::crt;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day08;
+
+impl Puzzle for Day08 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        8
+    }
+
+    fn title(&self) -> &'static str {
+        "Haunted Wasteland"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(steps_to_exit(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(steps_to_exit_multiple_starts(lines)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day08);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[
+    Example::new(
+        "RL\n\
+\n\
+AAA = (BBB, CCC)\n\
+BBB = (DDD, EEE)\n\
+CCC = (ZZZ, GGG)\n\
+DDD = (DDD, DDD)\n\
+EEE = (EEE, EEE)\n\
+GGG = (GGG, GGG)\n\
+ZZZ = (ZZZ, ZZZ)",
+        Some("2"),
+        None,
+    ),
+    Example::new(
+        "LR\n\
+        \n\
+11A = (11B, XXX)\n\
+11B = (XXX, 11Z)\n\
+11Z = (11B, XXX)\n\
+22A = (22B, XXX)\n\
+22B = (22C, 22C)\n\
+22C = (22Z, 22Z)\n\
+22Z = (22B, 22B)\n\
+XXX = (XXX, XXX)\n\
+",
+        None,
+        Some("6"),
+    ),
+];
+
+// The input is a list of instructions for map on a network of nodes
+// The first line is the sequence of L or R. At each node, we either go R for right, L for left.
+// After an empty line is the list of nodes, each with a name and the left and right nodes
+// So for example, if we have a line AAA = (BBB, CCC), then AAA is the name of the node, and BBB and CCC are the names of the left and right nodes, respectively
+// If we run out of instructions without reaching the end, we loop back to the beginning of instructions, so the instructions are infinite, ie LRR is actually LRRLRRLRR...
+// We want to find the number of steps to exit the network, where the start node is AAA and the end node is ZZZ
+fn steps_to_exit(input: &[&str]) -> Result<u64> {
+    let instructions = parse_instructions(input)?;
+    let nodes: Vec<(String, String, String)> = parse_nodes(input)?;
+    compute_steps_with_conditions("AAA", &instructions, &nodes, |node| node == "ZZZ")
+}
+
+fn parse_instructions(input: &[&str]) -> Result<Vec<char>> {
+    let instructions = input.first().copied().unwrap_or_default();
+    if instructions.is_empty() {
+        return Err(Error::new("expected a line of L/R instructions").at_line(1));
+    }
+    if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+        return Err(Error::new("instructions should only be L or R")
+            .at_line(1)
+            .at_column(i + 1));
+    }
+    Ok(instructions.chars().collect())
+}
+
+// The nodes start on the third line, after the instructions and a blank line
+fn parse_nodes(input: &[&str]) -> Result<Vec<(String, String, String)>> {
+    input
+        .iter()
+        .enumerate()
+        .skip(2)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            let (name, rest) = line
+                .split_once(" = ")
+                .and_then(|(name, rest)| {
+                    let rest = rest.strip_prefix('(')?.strip_suffix(')')?;
+                    Some((name, rest))
+                })
+                .ok_or_else(|| {
+                    Error::new("expected \"<node> = (<left>, <right>)\"").at_line(i + 1)
+                })?;
+            let (left, right) = rest
+                .split_once(", ")
+                .ok_or_else(|| Error::new("expected \"(<left>, <right>)\"").at_line(i + 1))?;
+            // println!("name: {:?}, left: {:?}, right: {:?}", name, left, right);
+            Ok((name.to_string(), left.to_string(), right.to_string()))
+        })
+        .collect()
+}
+
+// Given a node, a list of instructions, a list of nodes, and a stop condition, find the number of steps it takes to exit the network
+fn compute_steps_with_conditions<F>(
+    node: &str,
+    instructions: &[char],
+    nodes: &[(String, String, String)],
+    stop: F,
+) -> Result<u64>
+where
+    F: Fn(&str) -> bool,
+{
+    walk_to_exit(node, 0, instructions, nodes, stop).map(|(steps, _)| steps)
+}
+
+// The same, but starting `offset` steps into the instructions, and also giving the exit node reached.
+// There are only so many pairs of node and place in the instructions, so a walk that goes on
+// longer than that is going round in circles and will never reach an exit.
+fn walk_to_exit<'a, F>(
+    node: &'a str,
+    offset: usize,
+    instructions: &[char],
+    nodes: &'a [(String, String, String)],
+    stop: F,
+) -> Result<(u64, &'a str)>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut current_node = node;
+    let mut steps: usize = 0;
+    loop {
+        if steps == nodes.len() * instructions.len() {
+            return Err(Error::new(format!("no exit is reachable from {:?}", node)));
+        }
+        let node = nodes
+            .iter()
+            .find(|(name, _, _)| name == current_node)
+            .ok_or_else(|| Error::new(format!("no node named {:?}", current_node)))?;
+        let (_, left, right) = node;
+        let next_node = if instructions[(offset + steps) % instructions.len()] == 'L' {
+            left
+        } else {
+            right
+        };
+        if stop(next_node) {
+            steps += 1;
+            return Ok((steps as u64, next_node));
+        }
+        current_node = next_node;
+        steps += 1;
+    }
+}
+
+// In the second part, we note that there the number of nodes that end with the letter A and the letter Z are the same.
+// We are a ghost that exists at multiple nodes at once, and we want to find the number of steps it takes to exit the network.
+// So for example, if we have 11A = (11B, XXX) and 22A = (22B, XXX), then we start at 11A and 22A, and the first instruction is L, so we go to 11B and 22B.
+// We want to find the number of steps such that every version of us exits the network at a node ending with Z.
+// This cannot be brute-forced, so we need to find a way to calculate it.
+// Finding the smallest number of steps such that all nodes exit the network at the same time is equivalent to finding the least common multiple of the number of steps it takes for each node to exit the network.
+// ! No it's not, but it just happens to work anyway because of how the input is constructed.
+// ! See https://old.reddit.com/r/adventofcode/comments/18dfpub/ for discussion.
+// ! Consider three nodes, A, N, and Z. It takes h steps to go from A to N, takes m steps to go from N to Z, and takes t steps to go from Z to N (thus giving a cycle of length m + t with node Z). It just happens by construction of the puzzle input that h = t, and so the path from A to Z has length h + m = m + t. But this is not true in general.
+// ! Hypothetically, a general solution would use Chinese Remainder Theorem, however, even then we aren't guaranteed equal length cycles because it could be possible to hit multiple exit nodes in the same cycle.
+// So each ghost's first exit h and the cycle length c back to an exit are measured, and the answer is the first step
+// from the latest h on with step = h (mod c) for every ghost. This drops the h = t assumption, but still assumes
+// each ghost passes a single exit per cycle.
+fn steps_to_exit_multiple_starts(input: &[&str]) -> Result<u64> {
+    let instructions = parse_instructions(input)?;
+    let nodes = parse_nodes(input)?;
+    let starting_nodes = nodes
+        .iter()
+        .filter(|(name, _, _)| name.ends_with('A'))
+        .collect::<Vec<_>>();
+    if starting_nodes.is_empty() {
+        return Err(Error::new("expected some nodes ending in A to start from"));
+    }
+
+    let is_exit = |node: &str| node.ends_with('Z');
+    let cycles = starting_nodes
+        .iter()
+        .map(|&(name, _, _)| {
+            let (first, exit) = walk_to_exit(name, 0, &instructions, &nodes, is_exit)?;
+            let (cycle, _) = walk_to_exit(exit, first as usize, &instructions, &nodes, is_exit)?;
+            Ok((first as i64, cycle as i64))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (steps, period) =
+        crt(&cycles).ok_or_else(|| Error::new("the ghosts never reach their exits together"))?;
+    // The congruences also hold before a ghost first reaches its exit, so skip ahead past those
+    let latest_first = cycles.iter().map(|&(first, _)| first).max().unwrap_or(0);
+    let behind = (latest_first - steps).max(0);
+    Ok((steps + (behind + period - 1) / period * period) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day08_test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    fn day08_test_input_multiple_starts() -> &'static str {
+        EXAMPLES[1].input
+    }
+
+    #[test]
+    fn test_steps_to_exit() {
+        let input = day08_test_input();
+        // split into lines
+        let input: Vec<&str> = input.lines().collect();
+        assert_eq!(steps_to_exit(&input), Ok(2));
+    }
+
+    #[test]
+    fn test_steps_to_exit_multiple_starts() {
+        let input = day08_test_input_multiple_starts();
+        // split into lines
+        let input: Vec<&str> = input.lines().collect();
+        assert_eq!(steps_to_exit_multiple_starts(&input), Ok(6));
+    }
+
+    // A ghost whose first exit is 1 step away but then cycles every 3, with another that cycles
+    // every 2 from the start, which the lcm of the path lengths (1 and 2) would get wrong
+    #[test]
+    fn test_steps_to_exit_offset_cycles() {
+        let input = [
+            "L",
+            "",
+            "11A = (11Z, 11A)",
+            "11Z = (11B, 11B)",
+            "11B = (11C, 11C)",
+            "11C = (11Z, 11Z)",
+            "22A = (22B, 22B)",
+            "22B = (22Z, 22Z)",
+            "22Z = (22B, 22B)",
+        ];
+        assert_eq!(steps_to_exit_multiple_starts(&input), Ok(4));
+    }
+
+    #[test]
+    fn test_no_exit() {
+        let input = [
+            "LR",
+            "",
+            "AAA = (BBB, BBB)",
+            "BBB = (AAA, AAA)",
+            "ZZZ = (ZZZ, ZZZ)",
+        ];
+        let err = steps_to_exit(&input).unwrap_err();
+        assert_eq!(err.message, "no exit is reachable from \"AAA\"");
+        assert!(steps_to_exit_multiple_starts(&input).is_err());
+
+        let input = ["L", "", "BBB = (ZZZ, ZZZ)", "ZZZ = (ZZZ, ZZZ)"];
+        let err = steps_to_exit_multiple_starts(&input).unwrap_err();
+        assert_eq!(err.message, "expected some nodes ending in A to start from");
+    }
+}
@@ -1,18 +1,56 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::error::parse_number;
+use crate::etc::interpolation::{extrapolate_backward, extrapolate_forward};
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use rayon::prelude::*;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day09.txt").expect("Day 9 input file should be present.");
-    let input: Vec<&str> = input.lines().collect();
-    let sol1: i64 = sum_of_extrapolated_values(&input, false);
-    let sol2: i64 = sum_of_extrapolated_values(&input, true);
+pub struct Day09;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day09 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Mirage Maintenance"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_extrapolated_values(lines, false)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_extrapolated_values(lines, true)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day09);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    "0 3 6 9 12 15\n\
+1 3 6 10 15 21\n\
+10 13 16 21 30 45",
+    Some("114"),
+    Some("2"),
+)];
+
 // The input is list of space-separated sequences of integers (i64)
 // For each sequence, we extrapolate the next number in the sequence by looking at the sequence of differences between each pair of numbers
 // For example, the sequence 0 3 6 9 12 15 has differences 3 3 3 3 3. This difference sequence has differences 0 0 0 0, so the next number in the sequence is 18.
@@ -20,41 +58,37 @@ pub fn solve() -> SolutionPair {
 // In general, we can extrapolate the next number in the sequence by looking at the sequence of differences between each pair of numbers, and then looking at the sequence of differences between each pair of numbers in that sequence, and so on, until we reach a zero sequence, from which we can extrapolate the next number in the sequence.
 // Find the sum of the extrapolated values for each sequence.
 // In the second part, we extrapolate backward instead, getting the value before the first number in the sequence.
-fn sum_of_extrapolated_values(input: &[&str], backward: bool) -> i64 {
+// A sequence whose differences reach zero is a polynomial, so both directions just evaluate it
+// one step past either end (see etc::interpolation).
+fn sum_of_extrapolated_values(input: &[&str], backward: bool) -> Result<i64> {
     input
         .par_iter()
-        .map(|line| {
-            let mut numbers = line
+        .enumerate()
+        .map(|(i, line)| {
+            let numbers = line
                 .split_whitespace()
-                .map(|num| num.parse::<i64>().unwrap())
-                .collect::<Vec<_>>();
-            if backward {
-                numbers.reverse();
+                .map(parse_number::<i64>)
+                .collect::<Result<Vec<_>>>()
+                .map_err(|err| err.at_line(i + 1))?;
+            if numbers.is_empty() {
+                return Err(Error::new("expected a history of numbers").at_line(i + 1));
             }
-            extrapolate_next(&numbers)
+            let next = if backward {
+                extrapolate_backward(&numbers, 1)
+            } else {
+                extrapolate_forward(&numbers, 1)
+            };
+            next.ok_or_else(|| Error::new("the extrapolated value overflows an i64").at_line(i + 1))
         })
         .sum()
 }
 
-// This function extrapolates the next number in the sequence by looking at the sequence of differences between each pair of numbers, and then looking at the sequence of differences between each pair of numbers in that sequence, and so on, until we reach a zero sequence, from which we can extrapolate the next number in the sequence, which is the last number in the sequence plus the extrapolated next number in the sequence of differences.
-fn extrapolate_next(numbers: &[i64]) -> i64 {
-    let diffs: Vec<i64> = numbers.windows(2).map(|pair| pair[1] - pair[0]).collect();
-
-    if diffs.par_iter().all(|&x| x == 0) {
-        *numbers.last().unwrap()
-    } else {
-        numbers.last().unwrap() + extrapolate_next(&diffs)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn day07_test_input() -> &'static str {
-        "0 3 6 9 12 15\n\
-1 3 6 10 15 21\n\
-10 13 16 21 30 45"
+        EXAMPLES[0].input
     }
 
     #[test]
@@ -62,7 +96,7 @@ mod tests {
         let input = day07_test_input();
         // split into lines
         let input: Vec<&str> = input.lines().collect();
-        assert_eq!(sum_of_extrapolated_values(&input, false), 114);
+        assert_eq!(sum_of_extrapolated_values(&input, false), Ok(114));
     }
 
     #[test]
@@ -97,6 +131,6 @@ mod tests {
         // 2. 5 3 3 5 9 15
         // 1. 5 10 13 16 21 30 45
         // so 5 is the extrapolated value
-        assert_eq!(sum_of_extrapolated_values(&input, true), 2);
+        assert_eq!(sum_of_extrapolated_values(&input, true), Ok(2));
     }
 }
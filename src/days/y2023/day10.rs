@@ -0,0 +1,325 @@
+use crate::etc::geometry::{Direction4, Point2};
+use crate::etc::grid::{Grid, Pos};
+use crate::etc::polygon::Polygon;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+use hashbrown::HashMap;
+use std::collections::VecDeque;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        10
+    }
+
+    fn title(&self) -> &'static str {
+        "Pipe Maze"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(farthest_distance_in_loop(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(tiles_enclosed_by_loop(lines)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day10);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[
+    Example::new(
+        "..F7.\n\
+.FJ|.\n\
+SJ.L7\n\
+|F--J\n\
+LJ...",
+        Some("8"),
+        None,
+    ),
+    Example::new(
+        "FF7FSF7F7F7F7F7F---7\n\
+L|LJ||||||||||||F--J\n\
+FL-7LJLJ||||||LJL-77\n\
+F--JF--7||LJLJ7F7FJ-\n\
+L---JF-JLJ.||-FJLJJ7\n\
+|F|F-JF---7F7-L7L|7|\n\
+|FFJF7L7F-JF7|JL---7\n\
+7-L-JL7||F7|L7F-7F7|\n\
+L.L7LFJ|||||FJL7||LJ\n\
+L7JLJL-JLJLJL--JLJ.L",
+        None,
+        Some("10"),
+    ),
+];
+
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+enum TileType {
+    VerticalPipe,
+    HorizontalPipe,
+    NorthEastBend,
+    NorthWestBend,
+    SouthWestBend,
+    SouthEastBend,
+    Ground,
+}
+
+impl TileType {
+    // The pipe under S isn't known until its neighbours are, see parse_grid
+    fn from_char(c: char) -> Result<Self> {
+        match c {
+            '|' => Ok(Self::VerticalPipe),
+            '-' => Ok(Self::HorizontalPipe),
+            'L' => Ok(Self::NorthEastBend),
+            'J' => Ok(Self::NorthWestBend),
+            '7' => Ok(Self::SouthWestBend),
+            'F' => Ok(Self::SouthEastBend),
+            '.' | 'S' => Ok(Self::Ground),
+            _ => Err(Error::new(format!("invalid tile type {:?}", c))),
+        }
+    }
+
+    // The pipe connecting two directions, in either order
+    fn from_directions(directions: &[Direction4]) -> Option<Self> {
+        [
+            Self::VerticalPipe,
+            Self::HorizontalPipe,
+            Self::NorthEastBend,
+            Self::NorthWestBend,
+            Self::SouthWestBend,
+            Self::SouthEastBend,
+        ]
+        .into_iter()
+        .find(|tile| {
+            let valid = tile.valid_directions();
+            directions.len() == valid.len() && directions.iter().all(|dir| valid.contains(dir))
+        })
+    }
+
+    fn valid_directions(&self) -> Vec<Direction4> {
+        match self {
+            Self::VerticalPipe => vec![Direction4::Up, Direction4::Down],
+            Self::HorizontalPipe => vec![Direction4::Right, Direction4::Left],
+            Self::NorthEastBend => vec![Direction4::Up, Direction4::Right],
+            Self::NorthWestBend => vec![Direction4::Up, Direction4::Left],
+            Self::SouthWestBend => vec![Direction4::Down, Direction4::Left],
+            Self::SouthEastBend => vec![Direction4::Down, Direction4::Right],
+            Self::Ground => vec![],
+        }
+    }
+}
+
+// The input is a list of lines, each of which is a list of characters, creating a 2d grid of tiles.
+// | is a vertical pipe connecting north and south.
+// - is a horizontal pipe connecting east and west.
+// L is a 90-degree bend connecting north and east.
+// J is a 90-degree bend connecting north and west.
+// 7 is a 90-degree bend connecting south and west.
+// F is a 90-degree bend connecting south and east.
+// . is ground; there is no pipe in this tile.
+// S is the starting position of the animal; there is a pipe on this tile, but your sketch doesn't show what shape the pipe has.
+// The animal starts at S and moves through the pipes, which form a single loop.
+// There are tiles outside of the loop which we must ignore.
+// Find the distance from S to the farthest tile in the loop (going either way around the loop).
+fn farthest_distance_in_loop(input: &[&str]) -> Result<u64> {
+    let (grid, start_position) = parse_grid(input)?;
+    Ok(bfs(&grid, start_position))
+}
+
+// The grid and the starting position, with S replaced by the pipe that connects the two
+// neighbours whose pipes point back at it
+fn parse_grid(input: &[&str]) -> Result<(Grid<TileType>, Pos)> {
+    let mut grid = Grid::from_lines(input.iter().copied(), TileType::from_char)?;
+    let start_position = find_start_position(input)?;
+    let connected: Vec<Direction4> = Direction4::ALL
+        .into_iter()
+        .filter(|direction| {
+            grid.step(start_position, direction.delta())
+                .is_some_and(|neighbor| {
+                    grid[neighbor]
+                        .valid_directions()
+                        .contains(&direction.reverse())
+                })
+        })
+        .collect();
+    grid[start_position] = TileType::from_directions(&connected).ok_or_else(|| {
+        Error::new(format!(
+            "the start should connect to exactly two pipes, not {}",
+            connected.len()
+        ))
+        .at_line(start_position.0 + 1)
+        .at_column(start_position.1 + 1)
+    })?;
+    Ok((grid, start_position))
+}
+
+fn find_start_position(input: &[&str]) -> Result<Pos> {
+    for (i, row) in input.iter().enumerate() {
+        for (j, c) in row.chars().enumerate() {
+            if c == 'S' {
+                return Ok((i, j));
+            }
+        }
+    }
+
+    Err(Error::new("no start position S found"))
+}
+
+fn bfs(grid: &Grid<TileType>, start_position: Pos) -> u64 {
+    let mut queue = VecDeque::new();
+    let mut visited = HashMap::new();
+
+    queue.push_back((start_position, 0));
+
+    while let Some((position, distance)) = queue.pop_front() {
+        if visited.contains_key(&position) {
+            continue;
+        }
+        visited.insert(position, distance);
+
+        let tile = &grid[position];
+
+        // Add valid neighbors to the queue
+        let neighbors = get_valid_neighbors(position, tile, grid);
+        for neighbor in neighbors {
+            if !visited.contains_key(&neighbor) {
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+
+    *visited.values().max().unwrap()
+}
+
+// Based on the tile type, get the valid neighbors of the tile.
+fn get_valid_neighbors(pos: Pos, tile: &TileType, grid: &Grid<TileType>) -> Vec<Pos> {
+    // valid_directions returns a list of directions that are valid for the tile type, if it's ground, then there are no valid directions, so we need to handle that case too
+    let valid_directions = tile.valid_directions();
+
+    // A neighbor is only connected if its own pipe points back at this tile
+    valid_directions
+        .iter()
+        .filter_map(|direction| {
+            let neighbor = grid.step(pos, direction.delta())?;
+            grid[neighbor]
+                .valid_directions()
+                .contains(&direction.reverse())
+                .then_some(neighbor)
+        })
+        .collect()
+}
+
+// In the second part, we find the number of tiles enclosed by the loop.
+fn tiles_enclosed_by_loop(input: &[&str]) -> Result<u64> {
+    let (grid, start_position) = parse_grid(input)?;
+    // JORDAN CURVE THEOREM: A simple closed curve divides the plane into two regions, the inside and the outside.
+    // The loop is a simple closed curve through the centres of its tiles, so it's a polygon whose corners are
+    // the bends, and the enclosed tiles are exactly the lattice points strictly inside it.
+    // Pick's theorem gets those from the polygon's area and the number of tiles on the loop.
+    let loop_tiles = trace_loop(&grid, start_position)?;
+    let polygon = Polygon::new(loop_tiles.into_iter().map(Point2::from_pos).collect())?;
+
+    Ok(polygon.interior_points())
+}
+
+// The loop's tiles in order, following the pipes from the start until they lead back to it.
+// Each tile connects to at most two others, so the path can't close up anywhere but the start.
+fn trace_loop(grid: &Grid<TileType>, start_position: Pos) -> Result<Vec<Pos>> {
+    let mut path = vec![start_position];
+    let mut previous = None;
+    let mut position = start_position;
+    loop {
+        let next = get_valid_neighbors(position, &grid[position], grid)
+            .into_iter()
+            .find(|&neighbor| Some(neighbor) != previous)
+            .ok_or_else(|| Error::new("the pipes from S should form a loop"))?;
+        if next == start_position {
+            return Ok(path);
+        }
+        previous = Some(position);
+        position = next;
+        path.push(position);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day10_test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    fn day10_test_input_2() -> &'static str {
+        EXAMPLES[1].input
+    }
+
+    #[test]
+    fn test_farthest_distance_in_loop() {
+        let input = day10_test_input();
+        let input: Vec<&str> = input.lines().collect();
+        assert_eq!(farthest_distance_in_loop(&input), Ok(8));
+    }
+
+    #[test]
+    fn test_invalid_tile() {
+        let input = ["..F7.", ".FJ|.", "SJ.X7"];
+        let err = farthest_distance_in_loop(&input).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(4)));
+        let err = farthest_distance_in_loop(&["..F7."]).unwrap_err();
+        assert_eq!(err.message, "no start position S found");
+    }
+
+    #[test]
+    fn test_tiles_enclosed_by_loop() {
+        let input1 = day10_test_input();
+        let input1: Vec<&str> = input1.lines().collect();
+        assert_eq!(tiles_enclosed_by_loop(&input1), Ok(1));
+        let input2 = day10_test_input_2();
+        let input2: Vec<&str> = input2.lines().collect();
+        assert_eq!(tiles_enclosed_by_loop(&input2), Ok(10));
+    }
+
+    #[test]
+    fn test_start_tile() {
+        let input: Vec<&str> = day10_test_input().lines().collect();
+        let (grid, start_position) = parse_grid(&input).unwrap();
+        assert_eq!(start_position, (2, 0));
+        assert_eq!(grid[start_position], TileType::SouthEastBend);
+        let input: Vec<&str> = day10_test_input_2().lines().collect();
+        let (grid, start_position) = parse_grid(&input).unwrap();
+        assert_eq!(grid[start_position], TileType::SouthWestBend);
+
+        let err = parse_grid(&[".|.", "-S-", "..."]).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the start should connect to exactly two pipes, not 3"
+        );
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    }
+
+    #[test]
+    fn test_loop_not_closed() {
+        let input = ["S-7", "|.|", "L-."];
+        let err = tiles_enclosed_by_loop(&input).unwrap_err();
+        assert_eq!(err.message, "the pipes from S should form a loop");
+    }
+}
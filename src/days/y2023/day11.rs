@@ -1,53 +1,85 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::grid::Grid;
+use crate::etc::{Example, Puzzle, Result, Solution};
+use crate::register;
 use hashbrown::HashSet;
 use rayon::prelude::*;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day11.txt").expect("Day 11 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1: u64 = sum_pairwise_space_distances(&lines, 2);
-    let sol2: u64 = sum_pairwise_space_distances(&lines, 1_000_000);
+pub struct Day11;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day11 {
+    type Input<'a> = Grid<char>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Cosmic Expansion"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_pairwise_space_distances(grid, 2)))
+    }
+
+    fn part2(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_pairwise_space_distances(
+            grid, 1_000_000,
+        )))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day11);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."#,
+    Some("374"),
+    None,
+)];
+
 // The input is a 2d grid of characters with empty space (.) and galaxies (#)
 // We need to get the distance of the shortest path between all pairs of galaxies on this grid (ie the shortest path between them)
 // However, some space expands. In particular, a row of empty space counts as n rows of empty space, and a column of empty space counts as n columns of empty space.
 // In the first part, n is 2. In the second part, n is 1,000,000.
-fn sum_pairwise_space_distances(input: &[&str], expansion_factor: u64) -> u64 {
-    let grid: Vec<Vec<char>> = input
-        .par_iter()
-        .map(|line| line.chars().collect())
-        .collect();
+fn sum_pairwise_space_distances(grid: &Grid<char>, expansion_factor: u64) -> u64 {
     let mut sum = 0;
 
     // Count expanded rows and columns
-    let expanded_rows: HashSet<usize> = (0..grid.len())
-        .filter(|&i| grid[i].par_iter().all(|&c| c == '.'))
+    let expanded_rows: HashSet<usize> = (0..grid.rows())
+        .filter(|&i| grid.row(i).par_iter().all(|&c| c == '.'))
         .collect();
-    let expanded_columns: HashSet<usize> = (0..grid[0].len())
-        .filter(|&i| grid.par_iter().all(|row| row[i] == '.'))
+    let expanded_columns: HashSet<usize> = (0..grid.cols())
+        .filter(|&j| grid.column(j).all(|&c| c == '.'))
         .collect();
 
     // Collect galaxy coordinates
     let galaxy_coords: Vec<(usize, usize)> = grid
-        .par_iter()
-        .enumerate()
-        .flat_map(|(i, row)| {
-            row.par_iter().enumerate().filter_map(
-                move |(j, &col)| {
-                    if col == '#' {
-                        Some((i, j))
-                    } else {
-                        None
-                    }
-                },
-            )
-        })
+        .cells()
+        .filter(|&(_, &c)| c == '#')
+        .map(|(pos, _)| pos)
         .collect();
 
     // Calculate distances
@@ -97,16 +129,7 @@ mod tests {
     use super::*;
 
     fn test_input() -> &'static str {
-        r#"...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#....."#
+        EXAMPLES[0].input
     }
 
     fn simple_input() -> &'static str {
@@ -118,21 +141,21 @@ mod tests {
     #[test]
     fn test_simple_input() {
         let input = simple_input();
-        let input: Vec<&str> = input.lines().collect();
-        assert_eq!(sum_pairwise_space_distances(&input, 100), 202);
+        let grid = Grid::parse_chars(input).unwrap();
+        assert_eq!(sum_pairwise_space_distances(&grid, 100), 202);
     }
 
     #[test]
     fn test_sum_pairwise_space_distances() {
         let input = test_input();
-        let input: Vec<&str> = input.lines().collect();
-        assert_eq!(sum_pairwise_space_distances(&input, 2), 374);
+        let grid = Grid::parse_chars(input).unwrap();
+        assert_eq!(sum_pairwise_space_distances(&grid, 2), 374);
     }
 
     #[test]
     fn test_sum_pairwise_space_distances_larger() {
         let input = test_input();
-        let input: Vec<&str> = input.lines().collect();
-        assert_eq!(sum_pairwise_space_distances(&input, 100), 8410);
+        let grid = Grid::parse_chars(input).unwrap();
+        assert_eq!(sum_pairwise_space_distances(&grid, 100), 8410);
     }
 }
@@ -1,19 +1,59 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::error::parse_number;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use hashbrown::HashMap;
 use rayon::prelude::*;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day12.txt").expect("Day 12 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1: u64 = sum_of_arrangements(&lines);
-    let sol2: u64 = sum_of_folded_arrangements(&lines);
+pub struct Day12;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day12 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        12
+    }
+
+    fn title(&self) -> &'static str {
+        "Hot Springs"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_arrangements(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_of_folded_arrangements(lines)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day12);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1"#,
+    Some("21"),
+    Some("525152"),
+)];
+
 // Input is a list of rows, split into two parts by a space.
 // The first part is a string of dots and question marks, and hashes. This represents springs which may be operational (.), unknown (?), or broken (#).
 // The second part is a list of numbers separated by commas. These numbers represent groups of broken springs, where a group is a contiguous sequence of broken springs.
@@ -21,28 +61,41 @@ pub fn solve() -> SolutionPair {
 // Note that only one actual broken spring is shown. We have to find out where the other broken springs are.
 // There are four possible ways to arrange these groups based on the unknown springs.
 // Find the sum of the number of ways to arrange the groups for each line of input.
-fn sum_of_arrangements(input: &[&str]) -> u64 {
-    input.par_iter().map(|line| spring_arrangements(line)).sum()
+fn sum_of_arrangements(input: &[&str]) -> Result<u64> {
+    input
+        .par_iter()
+        .enumerate()
+        .map(|(i, line)| spring_arrangements(line).map_err(|err| err.at_line(i + 1)))
+        .sum()
 }
 
-fn sum_of_folded_arrangements(input: &[&str]) -> u64 {
+fn sum_of_folded_arrangements(input: &[&str]) -> Result<u64> {
     input
         .par_iter()
-        .map(|line| folded_spring_arrangements(line))
+        .enumerate()
+        .map(|(i, line)| folded_spring_arrangements(line).map_err(|err| err.at_line(i + 1)))
         .sum()
 }
 
+// Each line is a row of springs, a space, and the comma-separated group sizes, eg ???.### 1,1,3
+fn parse_record(line: &str) -> Result<(&str, Vec<usize>)> {
+    let (springs, groups) = line
+        .split_once(' ')
+        .ok_or_else(|| Error::new("expected \"<springs> <groups>\""))?;
+    if let Some(i) = springs.find(|c| !matches!(c, '.' | '#' | '?')) {
+        return Err(Error::new("springs should be one of . # ?").at_column(i + 1));
+    }
+    let groups = groups
+        .split(',')
+        .map(parse_number)
+        .collect::<Result<Vec<usize>>>()?;
+    Ok((springs, groups))
+}
+
 // Folded arrangements are just lines where the springs and groups are replaced with five copies of themselves, with ? between each copy
 // So .# 1 becomes .#?.#?.#?.#?.# 1,1,1,1,1
-fn folded_spring_arrangements(line: &str) -> u64 {
-    let mut parts = line.split(' ');
-    let springs = parts.next().unwrap();
-    let groups: Vec<usize> = parts
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|s| s.parse().unwrap())
-        .collect();
+fn folded_spring_arrangements(line: &str) -> Result<u64> {
+    let (springs, groups) = parse_record(line)?;
 
     let mut folded_springs = String::new();
     for i in 0..5 {
@@ -57,28 +110,21 @@ fn folded_spring_arrangements(line: &str) -> u64 {
         folded_groups.extend(groups.iter().cloned());
     }
 
-    let arrangements = calculate_arrangements(&folded_springs.as_bytes(), &folded_groups);
+    let arrangements = calculate_arrangements(folded_springs.as_bytes(), &folded_groups);
 
     // print folded
     // println!("{} {:?}: {}", folded_springs, folded_groups, arrangements);
-    arrangements as u64
+    Ok(arrangements as u64)
 }
 
-fn spring_arrangements(line: &str) -> u64 {
-    let mut parts = line.split(' ');
-    let springs = parts.next().unwrap();
-    let groups: Vec<usize> = parts
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|s| s.parse().unwrap())
-        .collect();
+fn spring_arrangements(line: &str) -> Result<u64> {
+    let (springs, groups) = parse_record(line)?;
 
-    let arrangements = calculate_arrangements(&springs.as_bytes(), &groups);
+    let arrangements = calculate_arrangements(springs.as_bytes(), &groups);
 
     // println!("{}: {}", line, arrangements);
 
-    arrangements as u64
+    Ok(arrangements as u64)
 }
 
 fn calculate_arrangements(springs: &[u8], groups: &[usize]) -> usize {
@@ -130,10 +176,12 @@ fn calculate_arrangements(springs: &[u8], groups: &[usize]) -> usize {
         // Case 2: The current spring is operational, and we have a current group
         // Check that there are more groups to place, and that the group is the expected length
         // Then we can move on to the next spring and the next group.
-        if (current_spring == b'.' || current_spring == b'?') && group_length > 0 {
-            if group_index < groups.len() && group_length == groups[group_index] {
-                arrangements += dp(springs, groups, spring_index + 1, group_index + 1, 0, memo);
-            }
+        if (current_spring == b'.' || current_spring == b'?')
+            && group_length > 0
+            && group_index < groups.len()
+            && group_length == groups[group_index]
+        {
+            arrangements += dp(springs, groups, spring_index + 1, group_index + 1, 0, memo);
         }
 
         // Case 3: Suppose the current spring is broken, so it must be part of a group.
@@ -170,27 +218,30 @@ mod tests {
 
     #[test]
     fn test_spring_arrangements() {
-        assert_eq!(spring_arrangements(". 1"), 0);
-        assert_eq!(spring_arrangements("? 1"), 1);
-        assert_eq!(spring_arrangements("?? 1"), 2);
-        assert_eq!(spring_arrangements("??# 2"), 1);
-        assert_eq!(spring_arrangements("???.### 1,1,3"), 1);
-        assert_eq!(spring_arrangements("#?#.??? 1,1,2"), 2);
-        assert_eq!(spring_arrangements(".??..??...?##. 1,1,3"), 4);
-        assert_eq!(spring_arrangements("?#?#?#?#?#?#?#? 1,3,1,6"), 1);
-        assert_eq!(spring_arrangements("????.#...#... 4,1,1"), 1);
-        assert_eq!(spring_arrangements("????.######..#####. 1,6,5"), 4);
-        assert_eq!(spring_arrangements("?###???????? 3,2,1"), 10);
-        assert_eq!(spring_arrangements(".?????...? 1,1,1"), 7);
+        assert_eq!(spring_arrangements(". 1"), Ok(0));
+        assert_eq!(spring_arrangements("? 1"), Ok(1));
+        assert_eq!(spring_arrangements("?? 1"), Ok(2));
+        assert_eq!(spring_arrangements("??# 2"), Ok(1));
+        assert_eq!(spring_arrangements("???.### 1,1,3"), Ok(1));
+        assert_eq!(spring_arrangements("#?#.??? 1,1,2"), Ok(2));
+        assert_eq!(spring_arrangements(".??..??...?##. 1,1,3"), Ok(4));
+        assert_eq!(spring_arrangements("?#?#?#?#?#?#?#? 1,3,1,6"), Ok(1));
+        assert_eq!(spring_arrangements("????.#...#... 4,1,1"), Ok(1));
+        assert_eq!(spring_arrangements("????.######..#####. 1,6,5"), Ok(4));
+        assert_eq!(spring_arrangements("?###???????? 3,2,1"), Ok(10));
+        assert_eq!(spring_arrangements(".?????...? 1,1,1"), Ok(7));
     }
 
     #[test]
     fn test_spring_arrangements_edge_case() {
-        assert_eq!(spring_arrangements("???#?? 1,1"), 3);
+        assert_eq!(spring_arrangements("???#?? 1,1"), Ok(3));
     }
 
     #[test]
     fn test_folded_spring_arrangements() {
-        assert_eq!(folded_spring_arrangements(".??..??...?##. 1,1,3"), 16384);
+        assert_eq!(
+            folded_spring_arrangements(".??..??...?##. 1,1,3"),
+            Ok(16384)
+        );
     }
 }
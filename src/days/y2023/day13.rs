@@ -1,19 +1,67 @@
-use crate::{Solution, SolutionPair};
-use core::panic;
-use rayon::prelude::*;
-use std::fs::read_to_string;
+use crate::etc::grid::Grid;
+use crate::etc::{Example, Puzzle, Result, Solution};
+use crate::register;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day13.txt").expect("Day 13 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1: u64 = sum_pattern_summary(&lines, 0);
-    let sol2: u64 = sum_pattern_summary(&lines, 1);
+pub struct Day13;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day13 {
+    type Input<'a> = Vec<Grid<char>>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Point of Incidence"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let lines: Vec<&str> = input.lines().collect();
+        parse_patterns(&lines)
+    }
+
+    fn part1(&self, grids: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_pattern_summary(grids, 0)))
+    }
+
+    fn part2(&self, grids: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_pattern_summary(grids, 1)))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day13);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#"#,
+    Some("405"),
+    Some("400"),
+)];
+
 // This struct represents the number of differences between columns/rows among their lines of reflection
 // Eg columns[0] = 1 means that the difference between column 0 and column 1 is 1 character
 // columns[1] = 4 means that there are 4 different characters between column 1 and column 2/column 0 and column 3
@@ -24,30 +72,30 @@ struct DifferenceCount {
 }
 
 // Check each possible line of reflection between columns and rows, and count how many differences there are between the two sides of the line of reflection
-fn compute_difference_count(grid: &[Vec<char>]) -> DifferenceCount {
-    let mut columns = vec![0; grid[0].len() - 1];
-    let mut rows = vec![0; grid.len() - 1];
+fn compute_difference_count(grid: &Grid<char>) -> DifferenceCount {
+    let mut columns = vec![0; grid.cols() - 1];
+    let mut rows = vec![0; grid.rows() - 1];
 
-    for i in 1..grid[0].len() {
+    for i in 1..grid.cols() {
         for k in 1..=i {
-            if i + k - 1 >= grid[0].len() {
+            if i + k > grid.cols() {
                 break;
             }
-            for row in grid {
-                if row[i - k] != row[i + k - 1] {
+            for (a, b) in grid.column(i - k).zip(grid.column(i + k - 1)) {
+                if a != b {
                     columns[i - 1] += 1;
                 }
             }
         }
     }
 
-    for i in 1..grid.len() {
+    for i in 1..grid.rows() {
         for k in 1..=i {
-            if i + k - 1 >= grid.len() {
+            if i + k > grid.rows() {
                 break;
             }
-            for j in 0..grid[0].len() {
-                if grid[i - k][j] != grid[i + k - 1][j] {
+            for (a, b) in grid.row(i - k).iter().zip(grid.row(i + k - 1)) {
+                if a != b {
                     rows[i - 1] += 1;
                 }
             }
@@ -63,12 +111,7 @@ fn compute_difference_count(grid: &[Vec<char>]) -> DifferenceCount {
 // This means that column 5 and 6 are identical, and so are 4 and 7, 3 and 8, etc. Some columns won't have a reflection, but we can just ignore those.
 // To summarize your pattern notes, add the number of columns to the left of the vertical line of reflection or 100 times the number of rows above the horizontal line of reflection.
 // Find the sum of the pattern summaries of the grids.
-fn sum_pattern_summary(input: &[&str], smudges: usize) -> u64 {
-    let grids: Vec<Vec<Vec<char>>> = input
-        .split(|x| x.is_empty())
-        .map(|x| x.iter().map(|y| y.chars().collect()).collect())
-        .collect();
-
+fn sum_pattern_summary(grids: &[Grid<char>], smudges: usize) -> u64 {
     grids
         .iter()
         .map(|grid| {
@@ -93,33 +136,43 @@ fn sum_pattern_summary(input: &[&str], smudges: usize) -> u64 {
         .sum()
 }
 
+// Patterns are separated by blank lines, and every row of a pattern must have the same width.
+fn parse_patterns(input: &[&str]) -> Result<Vec<Grid<char>>> {
+    let mut grids = Vec::new();
+    let mut start = 0;
+
+    for pattern in input.split(|line| line.is_empty()) {
+        if !pattern.is_empty() {
+            let grid = Grid::from_lines(pattern.iter().copied(), Ok).map_err(|mut err| {
+                // The grid only knows the line within its pattern
+                err.line = err.line.map(|line| line + start);
+                err
+            })?;
+            grids.push(grid);
+        }
+        start += pattern.len() + 1;
+    }
+
+    Ok(grids)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn test_input_sum() -> &'static str {
-        r#"#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#"#
+        EXAMPLES[0].input
     }
 
     #[test]
     fn test_pattern_summary_sum() {
         let input = test_input_sum();
         let input = input.lines().collect::<Vec<&str>>();
-        assert_eq!(sum_pattern_summary(&input, 0), 405);
+        let grids = parse_patterns(&input).unwrap();
+        assert_eq!(sum_pattern_summary(&grids, 0), 405);
+
+        let err = parse_patterns(&["#.", "..", "", "#.#", ".#"]).unwrap_err();
+        assert_eq!(err.line, Some(5));
     }
 
     #[test]
@@ -127,10 +180,7 @@ mod tests {
         let input = r#"#.#
 #.#
 #.."#;
-        let input: Vec<Vec<char>> = input
-            .lines()
-            .map(|x| x.chars().collect::<Vec<char>>())
-            .collect();
+        let input = Grid::parse_chars(input).unwrap();
         let expected = DifferenceCount {
             columns: vec![3, 2],
             rows: vec![0, 1],
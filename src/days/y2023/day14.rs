@@ -0,0 +1,272 @@
+use crate::etc::grid::Grid;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+use hashbrown::HashMap;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day14;
+
+impl Puzzle for Day14 {
+    type Input<'a> = Grid<char>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        14
+    }
+
+    fn title(&self) -> &'static str {
+        "Parabolic Reflector Dish"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_platform(input)
+    }
+
+    fn part1(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(total_load(grid)))
+    }
+
+    fn part2(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(total_load_cycles(grid, 1_000_000_000)))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day14);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#...."#,
+    Some("136"),
+    Some("64"),
+)];
+
+// Each tilt of a cycle rolls the rocks north and then turns the platform clockwise,
+// so that the next direction of the cycle is north in turn
+fn total_load_cycles(grid: &Grid<char>, total_cycles: u64) -> u64 {
+    let mut grid = grid.clone();
+
+    // let's hash the grid and see if we've seen it before.
+    // If so, find 1000000000 % cycle_length and do that many cycles
+    // then calculate total load
+
+    let mut seen = HashMap::new();
+    let mut first_occurrence = 0;
+
+    let mut cycle_length = None;
+    for i in 0..total_cycles {
+        let hash = hash_grid(&grid);
+        if let Some(&occurence) = seen.get(&hash) {
+            // This is basically Floyd's cycle detection algorithm
+            first_occurrence = occurence;
+            cycle_length = Some(i - first_occurrence);
+            // println!("Cycle length: {}", cycle_length.unwrap());
+            // println!(
+            //     "First occurence index: {}, Current index: {}",
+            //     first_occurrence, i
+            // );
+            break;
+        }
+        seen.insert(hash, i);
+        roll_cycle(&mut grid);
+    }
+
+    // First occurrence: 102, Current: 112 (10 cycles)
+    // So we see the same grid after 10 steps, at 122, 132, 142, etc.
+    // We want the equivalent to 1000000000 steps
+
+    let cycle_length = cycle_length.unwrap_or(total_cycles);
+
+    // Calculate the total number of complete cycles within the remaining steps
+    let remaining_steps = total_cycles - first_occurrence;
+
+    // Calculate the remaining cycles after the last complete cycle
+    let remaining_cycles = remaining_steps % cycle_length;
+
+    // let final_index = first_occurrence + remaining_cycles;
+    // println!("Final index: {}", final_index);
+    // So it should be the grid at index 118
+
+    // Perform the transformations for the remaining cycles
+    for _ in 0..remaining_cycles {
+        roll_cycle(&mut grid);
+    }
+
+    calculate_total_load(&grid)
+}
+
+// The platform is a rectangle of round rocks (O), cube rocks (#) and spaces (.)
+fn parse_platform(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input, |c| match c {
+        'O' | '#' | '.' => Ok(c),
+        _ => Err(Error::new("expected only O, # and .")),
+    })
+}
+
+fn hash_grid(grid: &Grid<char>) -> u64 {
+    // ! THIS WAS WRONG and lead to my main time sink, didn't even realize it for a while, 100x100 grid, so 10000 bits, but u64 is only 64 bits, so it was truncating the hash, resulting in overflows. I'm keeping this here as a reminder to be careful with bit operations
+    // ! This is based on my old row hashing function, but it's not quite right, since we're hashing columns now, but I'm still keeping it here.
+    // let mut hash = 0;
+    // for (i, row) in chars.iter().enumerate() {
+    //     for (j, &c) in row.iter().enumerate() {
+    //         if c == 'O' {
+    //             hash ^= 1 << (i * chars.len() + j);
+    //         }
+    //     }
+    // }
+    // hash
+    let mut hash: u64 = 0;
+    for ((i, j), &c) in grid.cells() {
+        if c == 'O' {
+            // Using a hash function that avoids overflow
+            hash = hash.wrapping_add(((i * grid.cols() + j) as u64).wrapping_mul(2654435761));
+        }
+    }
+    hash
+}
+
+// Input is a 2d grid of round rocks (O), cube rocks (#), and spaces (.)
+// You can tilt the grid in four directions (up, down, left, right)
+// When you tilt the grid, the rounded rocks roll in that direction until they hit a edge of the grid or another rock.
+// The load of a rock is the number of rows including itself to the south edge of the grid
+// So a rock on the bottom row has a load of 1, a rock on the second to last row has a load of 2, etc.
+// Tilt the platform so that all the rounded rocks roll north.
+// Find the sum of the loads of all the rounded rocks.
+fn total_load(grid: &Grid<char>) -> u64 {
+    let mut grid = grid.clone();
+    roll_north(&mut grid);
+    calculate_total_load(&grid)
+}
+
+fn calculate_total_load(grid: &Grid<char>) -> u64 {
+    let mut total_load = 0;
+    for ((i, _), &c) in grid.cells() {
+        if c == 'O' {
+            total_load += grid.rows() - i;
+        }
+    }
+
+    total_load as u64
+}
+
+// Perform a north, west, south, east roll cycle on the grid
+fn roll_cycle(grid: &mut Grid<char>) {
+    for _ in 0..4 {
+        roll_north(grid);
+        *grid = grid.rotate_cw();
+    }
+}
+
+// For each column, move the rocks 'O' up to the next # or the top of the grid
+fn roll_north(grid: &mut Grid<char>) {
+    for j in 0..grid.cols() {
+        // The row the next rock in this column comes to rest on
+        let mut free = 0;
+        for i in 0..grid.rows() {
+            match grid[(i, j)] {
+                '#' => free = i + 1,
+                'O' => {
+                    grid[(i, j)] = '.';
+                    grid[(free, j)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    fn test_expected_after_1_cycle() -> &'static str {
+        r#".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#...."#
+    }
+
+    fn test_expected_after_2_cycles() -> &'static str {
+        r#".....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O"#
+    }
+
+    #[test]
+    fn test_rotate_grid_cw() {
+        let input = r#"#.
+O."#;
+        let expected = r#"O#
+.."#;
+        let grid = parse_platform(input).unwrap();
+        assert_eq!(grid.rotate_cw(), parse_platform(expected).unwrap());
+    }
+
+    #[test]
+    fn test_roll_north() {
+        let input = r#"#.
+O.
+.O
+OO"#;
+        let expected = r#"#O
+OO
+O.
+.."#;
+        let mut grid = parse_platform(input).unwrap();
+        roll_north(&mut grid);
+        assert_eq!(grid, parse_platform(expected).unwrap());
+    }
+
+    #[test]
+    fn test_multiple_cycles() {
+        let mut grid = parse_platform(test_input()).unwrap();
+        roll_cycle(&mut grid);
+        assert_eq!(grid, parse_platform(test_expected_after_1_cycle()).unwrap());
+
+        roll_cycle(&mut grid);
+        assert_eq!(
+            grid,
+            parse_platform(test_expected_after_2_cycles()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_invalid_platform() {
+        let err = parse_platform("O.\n.x").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    }
+}
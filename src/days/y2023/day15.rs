@@ -1,16 +1,51 @@
-use crate::{Solution, SolutionPair};
-use std::{fs::read_to_string, hash};
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day15.txt").expect("Day 15 input file should be present");
-    let sol1: u64 = sum_hash_algorithm(&input);
-    let sol2: u64 = initialization_sequence(&input);
+pub struct Day15;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day15 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        15
+    }
+
+    fn title(&self) -> &'static str {
+        "Lens Library"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_steps(input))
+    }
+
+    fn part1(&self, steps: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_hash_algorithm(steps)))
+    }
+
+    fn part2(&self, steps: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(initialization_sequence(steps)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day15);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7",
+    Some("1320"),
+    Some("145"),
+)];
+
 // The HASH algorithm turns any string of characters into a integer 0-255
 // To run the algorithm, start with a current value of zero
 // For each character in the string starting from the beginning:
@@ -22,9 +57,14 @@ pub fn solve() -> SolutionPair {
 // The input is the init sequence, a comma-separated list of strings (ignore newlines)
 // For each string, run the HASH algorithm and get the result
 // Find the sum of the results
-fn sum_hash_algorithm(input: &str) -> u64 {
-    // remove newlines, split on commas, and run the hash algorithm on each string
-    input.trim().split(',').map(|s| hash_algorithm(s)).sum()
+// remove newlines and split on commas to get the steps
+fn parse_steps(input: &str) -> Vec<&str> {
+    input.trim().split(',').collect()
+}
+
+fn sum_hash_algorithm(steps: &[&str]) -> u64 {
+    // run the hash algorithm on each step
+    steps.iter().map(|step| hash_algorithm(step)).sum()
 }
 
 fn hash_algorithm(input: &str) -> u64 {
@@ -64,17 +104,18 @@ struct Lens {
     focal_length: u8,
 }
 
-fn initialization_sequence(input: &str) -> u64 {
+fn initialization_sequence(steps: &[&str]) -> Result<u64> {
     // Create the boxes
     let mut boxes: Vec<Box> = (0..256).map(|_| Box { lenses: vec![] }).collect();
 
-    input.trim().split(',').for_each(|step_str| {
+    for step_str in steps {
         // This is a step in the initialization sequence
         // Get the letters, then the operation, then the focal length if applicable
         let (label, rest) =
             step_str.split_at(step_str.find(|c: char| !c.is_alphabetic()).unwrap_or(0));
-        let (operation, rest) = rest.split_at(1);
-        let focal_length: u8 = rest.parse().unwrap_or(0);
+        let invalid_step = || Error::new(format!("invalid step {:?}", step_str));
+        let operation = rest.get(..1).ok_or_else(invalid_step)?;
+        let focal_length: u8 = rest[1..].parse().unwrap_or(0);
         // println!(
         //     "label: {}, operation: {}, focal_length: {}",
         //     label, operation, focal_length
@@ -103,7 +144,7 @@ fn initialization_sequence(input: &str) -> u64 {
                     });
                 }
             }
-            _ => panic!("Invalid operation"),
+            _ => return Err(invalid_step()),
         }
 
         // print all non empty boxes
@@ -112,9 +153,9 @@ fn initialization_sequence(input: &str) -> u64 {
         //         println!("Box {}: {:?}", i, box_to_print);
         //     }
         // }
-    });
+    }
 
-    boxes
+    Ok(boxes
         .iter()
         .enumerate()
         .map(|(i, box_to_print)| {
@@ -126,7 +167,7 @@ fn initialization_sequence(input: &str) -> u64 {
                 .map(|(j, lens)| (i + 1) * (j + 1) * lens.focal_length as usize)
                 .sum::<usize>() as u64
         })
-        .sum()
+        .sum())
 }
 
 #[cfg(test)]
@@ -136,12 +177,12 @@ mod tests {
     #[test]
     fn test_sum_hash_algorithm() {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!(sum_hash_algorithm(&input), 1320);
+        assert_eq!(sum_hash_algorithm(&parse_steps(input)), 1320);
     }
 
     #[test]
     fn test_initialization_sequence() {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        assert_eq!(initialization_sequence(&input), 145);
+        assert_eq!(initialization_sequence(&parse_steps(input)), Ok(145));
     }
 }
@@ -0,0 +1,175 @@
+use crate::etc::geometry::Direction4;
+use crate::etc::grid::Grid;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+use hashbrown::HashSet;
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::VecDeque;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    type Input<'a> = Grid<char>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        16
+    }
+
+    fn title(&self) -> &'static str {
+        "The Floor Will Be Lava"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_grid(input)
+    }
+
+    fn part1(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(energized_tiles(
+            grid,
+            0,
+            0,
+            Direction4::Right,
+        )))
+    }
+
+    fn part2(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(max_energized_tiles(grid)))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day16);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|...."#,
+    Some("46"),
+    Some("51"),
+)];
+
+fn parse_grid(input: &str) -> Result<Grid<char>> {
+    Grid::parse(input, |c| match c {
+        '.' | '/' | '\\' | '|' | '-' => Ok(c),
+        _ => Err(Error::new("expected only . / \\ | or -")),
+    })
+}
+
+// Input is a grid with empty space (.), mirrors (/) and (\), and splitters (|) and (-).
+// Beam of light starts at top left from left, heading right.
+// On empty space, beam continues in same direction.
+// At a mirror, the beam is reflected 90 degrees depending on the direction of the mirror,
+// eg a right-moving beam hitting / will be reflected upwards.
+// If the beam encounters the pointy end of a splitter, the beam passes through the splitter and continues in the same direction.
+// If the beam encounters the flat end of a splitter, the beam is split into the two perpendicular directions.
+// If the beam encounters the edge of the grid, it ends.
+// Beams do not interact with each other.
+// A tile is energized if that tile has at least one beam of light passing through it.
+// Find the number of energized tiles.
+fn energized_tiles(input: &Grid<char>, i: usize, j: usize, direction: Direction4) -> u64 {
+    let mut seen_beam: HashSet<(usize, usize, Direction4)> = HashSet::new();
+    let mut energized_tiles: HashSet<(usize, usize)> = HashSet::new();
+    let mut current_beam: VecDeque<(usize, usize, Direction4)> = VecDeque::new();
+    current_beam.push_back((i, j, direction));
+
+    while let Some((i, j, direction)) = current_beam.pop_front() {
+        if seen_beam.contains(&(i, j, direction)) {
+            continue;
+        }
+        seen_beam.insert((i, j, direction));
+        energized_tiles.insert((i, j));
+
+        let next_directions = match input[(i, j)] {
+            '/' => vec![direction.reflect_slash()],
+            '\\' => vec![direction.reflect_backslash()],
+            '|' if direction.is_horizontal() => vec![Direction4::Up, Direction4::Down],
+            '-' if direction.is_vertical() => vec![Direction4::Left, Direction4::Right],
+            // Empty space, or the pointy end of a splitter
+            _ => vec![direction],
+        };
+        // Beams that leave the grid end there
+        for next in next_directions {
+            if let Some((i, j)) = input.step((i, j), next.delta()) {
+                current_beam.push_back((i, j, next));
+            }
+        }
+    }
+
+    // for reference, create a new grid where each tile is replaced with # if it's energized and . if it's not
+    // let mut energized_grid = input.clone();
+    // energized_grid.fill('.');
+    // for (i, j, _) in seen_beam {
+    //     // energized_grid.set(i, j, '#');  // no set method in grid, have to take an iter
+    //     energized_grid[(i, j)] = '#';
+    // }
+    //
+    // energized_grid.iter_rows().for_each(|row| {
+    //     row.for_each(|tile| print!("{}", tile));
+    //     println!();
+    // });
+
+    energized_tiles.len() as u64
+}
+
+// Find the laser beam entry position that maximizes the number of energized tiles.
+fn max_energized_tiles(input: &Grid<char>) -> u64 {
+    // The possible positions are down from the top row, up from the bottom row,
+    // left from the left column, and right from the right column.
+    let possible_entries = (0..input.cols())
+        .map(|j| (0, j, Direction4::Down))
+        .chain((0..input.cols()).map(|j| (input.rows() - 1, j, Direction4::Up)))
+        .chain((0..input.rows()).map(|i| (i, 0, Direction4::Right)))
+        .chain((0..input.rows()).map(|i| (i, input.cols() - 1, Direction4::Left)))
+        .collect_vec();
+
+    // The grid is ~100x100, which means perimeter = 400. Run time for one energized_tiles() is ~15ms
+    // This would take 6 seconds to run through all 400 positions, which is perfectly fine.
+    // Using rayon, only took ~125ms, can certainly opti
+    possible_entries
+        .par_iter()
+        .map(|entry| {
+            let (i, j, direction) = entry;
+            energized_tiles(input, *i, *j, *direction)
+        })
+        .max()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    #[test]
+    fn test_energized_tiles() {
+        let input = parse_grid(test_input()).unwrap();
+        assert_eq!(energized_tiles(&input, 0, 0, Direction4::Right), 46);
+    }
+
+    #[test]
+    fn test_max_energized_tiles() {
+        let input = parse_grid(test_input()).unwrap();
+        assert_eq!(max_energized_tiles(&input), 51);
+    }
+}
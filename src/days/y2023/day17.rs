@@ -1,19 +1,77 @@
-use crate::{Solution, SolutionPair};
-use hashbrown::{HashMap, HashSet};
-use pathfinding::prelude::{astar, Matrix};
-use rayon::prelude::*;
-use std::fs::read_to_string;
+use crate::etc::geometry::Direction4;
+use crate::etc::grid::{Grid, Pos};
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+use pathfinding::prelude::astar;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day17.txt").expect("Day 17 input file should be present");
-    let sol1 = min_heat_loss(&input, 3, 1);
-    let sol2 = min_heat_loss(&input, 10, 4);
+pub struct Day17;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day17 {
+    type Input<'a> = Grid<u8>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        17
+    }
+
+    fn title(&self) -> &'static str {
+        "Clumsy Crucible"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_heat_map(input)
+    }
+
+    fn part1(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(min_heat_loss(grid, 3, 1)?))
+    }
+
+    fn part2(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(min_heat_loss(grid, 10, 4)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day17);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[
+    Example::new(
+        r#"2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533"#,
+        Some("102"),
+        Some("94"),
+    ),
+    Example::new(
+        r#"111111111111
+999999999991
+999999999991
+999999999991
+999999999991"#,
+        None,
+        Some("71"),
+    ),
+];
+
 // Input is a 2d grid of numbers representing the heat loss of each tile.
 // Goal is to find path for the crucible to minimize heat loss.
 // The start is the top left, and the end is the bottom right.
@@ -22,59 +80,32 @@ pub fn solve() -> SolutionPair {
 // The crucible can go at most 3 tiles in a straight line, then it must turn 90 degrees left or right.
 // The crucible also can't reverse direction, it can only ever turn left, turn right, or go straight.
 // In the second part, the crucible must go at least 4 tiles in a straight line and can go at most 10 tiles in a straight line.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-    None,
-}
-
-impl Direction {
-    fn to_delta(&self) -> (isize, isize) {
-        match self {
-            Direction::North => (-1, 0),
-            Direction::South => (1, 0),
-            Direction::East => (0, 1),
-            Direction::West => (0, -1),
-            Direction::None => (0, 0),
-        }
-    }
-
-    fn perpendicular_directions(&self) -> (Direction, Direction) {
-        match self {
-            Direction::North | Direction::South => (Direction::East, Direction::West),
-            Direction::East | Direction::West => (Direction::North, Direction::South),
-            Direction::None => (Direction::South, Direction::East),
-        }
-    }
-}
-
+// The crucible has no direction before its first move, when it can set off either way
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 struct Crucible {
-    position: (usize, usize),
-    direction: Direction,
+    position: Pos,
+    direction: Option<Direction4>,
 }
 
 fn successors(
-    grid: &Matrix<u8>,
+    grid: &Grid<u8>,
     state: &Crucible,
     max_run_length: usize,
     min_run_length: usize,
 ) -> Vec<(Crucible, usize)> {
     let mut next_crucibles = Vec::new();
-    let (left_turn, right_turn) = state.direction.perpendicular_directions();
-    let directions = vec![left_turn, right_turn];
+    let directions = match state.direction {
+        Some(direction) => [direction.turn_left(), direction.turn_right()],
+        None => [Direction4::Down, Direction4::Right],
+    };
 
     for dir in directions {
         // Calculate the heat loss moving along the straight line
         let mut heat_loss = 0;
         for run in 1..max_run_length + 1 {
             let (i, j) = state.position;
-            let delta = dir.to_delta();
-            let next_position =
-                grid.move_in_direction((i, j), (delta.0 * run as isize, delta.1 * run as isize));
+            let delta = dir.delta();
+            let next_position = grid.step((i, j), (delta.0 * run as isize, delta.1 * run as isize));
 
             if next_position.is_none() {
                 break;
@@ -88,7 +119,7 @@ fn successors(
                 next_crucibles.push((
                     Crucible {
                         position: next_position,
-                        direction: dir,
+                        direction: Some(dir),
                     },
                     heat_loss,
                 ));
@@ -101,7 +132,7 @@ fn successors(
 
 // Manhattan distance is not a good heuristic because long straight lines are not penalized enough.
 // To more accurately represent the cost of long straight lines, we use a slither pattern.
-fn heuristic(pos: (usize, usize), goal: (usize, usize), min_len: usize, max_len: usize) -> usize {
+fn heuristic(pos: Pos, goal: Pos, min_len: usize, max_len: usize) -> usize {
     let dx = isize::abs(pos.0 as isize - goal.0 as isize) as usize;
     let dy = isize::abs(pos.1 as isize - goal.1 as isize) as usize;
 
@@ -122,29 +153,34 @@ fn heuristic(pos: (usize, usize), goal: (usize, usize), min_len: usize, max_len:
     }
 }
 
-fn min_heat_loss(input: &str, max_len: usize, min_len: usize) -> usize {
-    let grid = input
-        .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap() as u8))
-        .collect::<Matrix<u8>>();
+pub fn parse_heat_map(input: &str) -> Result<Grid<u8>> {
+    Grid::parse(input, |c| {
+        c.to_digit(10)
+            .map(|digit| digit as u8)
+            .ok_or_else(|| Error::new(format!("expected a digit, found {:?}", c)))
+    })
+}
 
+// The least heat lost moving a crucible from the top left to the bottom right, when it has to go
+// between min_len and max_len tiles in a straight line before each turn
+pub fn min_heat_loss(grid: &Grid<u8>, max_len: usize, min_len: usize) -> Result<usize> {
     let start = Crucible {
         position: (0, 0),
-        direction: Direction::None,
+        direction: None,
     };
 
-    let end = (grid.rows - 1, grid.columns - 1);
+    let end = (grid.rows() - 1, grid.cols() - 1);
 
     let (_path, cost) = astar(
         &start,
-        |crucible| successors(&grid, crucible, max_len, min_len),
+        |crucible| successors(grid, crucible, max_len, min_len),
         |crucible| heuristic(crucible.position, end, min_len, max_len),
         |crucible| crucible.position == end,
     )
-    .unwrap();
+    .ok_or_else(|| Error::new("no path reaches the bottom-right corner"))?;
 
     // TODO: add some parameter to print the path
-    // let mut grid_view = grid.map(|num| char::from_digit(num as u32, 10).unwrap());
+    // let mut grid_view = grid.map(|&num| char::from_digit(num as u32, 10).unwrap());
     //
     // // Backtrack from the end to the start, marking the path
     // for (index, state) in _path.iter().enumerate().rev() {
@@ -155,33 +191,21 @@ fn min_heat_loss(input: &str, max_len: usize, min_len: usize) -> usize {
     //     let next_state = &_path[index - 1];
     //
     //     // Mark the path
-    //     let (di, dj) = state.direction.to_delta();
+    //     let direction = state.direction.unwrap();
     //     while current_position != next_state.position {
-    //         grid_view[current_position] = match state.direction {
-    //             Direction::North => '↑',
-    //             Direction::South => '↓',
-    //             Direction::East => '→',
-    //             Direction::West => '←',
-    //             _ => unreachable!(),
-    //         };
-    //         current_position = (
-    //             (current_position.0 as isize - di) as usize,
-    //             (current_position.1 as isize - dj) as usize,
-    //         );
+    //         grid_view[current_position] = direction.arrow();
+    //         current_position = grid
+    //             .step(current_position, direction.reverse().delta())
+    //             .unwrap();
     //     }
     // }
     //
     // grid_view[start.position] = '•'; // Start position marked with a dot
     //
     // // Print the grid with the path
-    // for row in grid_view.iter() {
-    //     for &cell in row.iter() {
-    //         print!("{}", cell);
-    //     }
-    //     println!();
-    // }
+    // println!("{}", grid_view);
 
-    cost
+    Ok(cost)
 }
 
 #[cfg(test)]
@@ -196,32 +220,29 @@ mod tests {
 
     #[test]
     fn test_min_heat_loss_simple() {
-        assert_eq!(min_heat_loss(test_input_simple(), 3, 1), 6);
+        assert_eq!(
+            min_heat_loss(&parse_heat_map(test_input_simple()).unwrap(), 3, 1),
+            Ok(6)
+        );
     }
 
     fn test_input() -> &'static str {
-        r#"2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533"#
+        EXAMPLES[0].input
     }
 
     #[test]
     fn test_min_heat_loss_sample() {
-        assert_eq!(min_heat_loss(test_input(), 3, 1), 102);
+        assert_eq!(
+            min_heat_loss(&parse_heat_map(test_input()).unwrap(), 3, 1),
+            Ok(102)
+        );
     }
 
     #[test]
     fn test_min_heat_loss_ultra() {
-        assert_eq!(min_heat_loss(test_input(), 10, 4), 94);
+        assert_eq!(
+            min_heat_loss(&parse_heat_map(test_input()).unwrap(), 10, 4),
+            Ok(94)
+        );
     }
 }
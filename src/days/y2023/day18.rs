@@ -0,0 +1,240 @@
+use crate::etc::error::parse_number;
+use crate::etc::geometry::{Direction4, Point2};
+use crate::etc::polygon::Polygon;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day18;
+
+impl Puzzle for Day18 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "Lavaduct Lagoon"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(lava_dig_plan(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(lava_dig_plan_alternate(lines)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day18);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)
+"#,
+    Some("62"),
+    Some("952408144115"),
+)];
+
+// The input is a line separated list of strings. Each string is a direction, a number of steps, and a color in RGB hex format.
+// The digger starts in a 1 meter cube hole in the ground, then they dig in the direction with the number of steps.
+// Each trench is also listed with the color that the edge of the trench should be painted.
+// The instructions create a loop of trenches that the digger will dig.
+// After these trenches are dug, the digger then digs out the interior of the loop.
+// Find the number of cubic meters of dirt that the digger will dig.
+fn lava_dig_plan(input: &[&str]) -> Result<u64> {
+    let steps = input
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_dig_step(line).map_err(|err| err.at_line(i + 1)))
+        .collect::<Result<Vec<_>>>()?;
+    let lagoon = dig_lagoon(steps)?;
+
+    // Every trench is a lattice point on the loop, and every interior trench one inside it
+    Ok(lagoon.lattice_points())
+}
+
+// The loop of trenches the steps dig, which has to close up without crossing itself for the
+// interior to make sense
+fn dig_lagoon(steps: Vec<(Direction4, i64)>) -> Result<Polygon> {
+    let lagoon = Polygon::from_steps(Point2::ORIGIN, steps)
+        .map_err(|err| Error::new(format!("the dig plan should be a loop, {}", err.message)))?;
+    if let Some(point) = lagoon.self_intersection() {
+        return Err(Error::new(format!(
+            "the dig plan should not cross itself, but does at {}",
+            point
+        )));
+    }
+    Ok(lagoon)
+}
+
+// Each line is a direction, a number of steps and a color, eg R 6 (#70c710)
+fn parse_dig_step(line: &str) -> Result<(Direction4, i64)> {
+    let mut parts = line.split_whitespace();
+    let dir = parts
+        .next()
+        .unwrap_or_default()
+        .parse::<Direction4>()
+        .map_err(|err| err.at_column(1))?;
+    let steps = parse_number::<u32>(parts.next().unwrap_or_default())?;
+    Ok((dir, steps as i64))
+}
+
+// The last hex digit of a color is the direction, 0 to 3 for R, D, L and U
+fn direction_from_digit(n: u32) -> Result<Direction4> {
+    match n {
+        0 => Ok(Direction4::Right),
+        1 => Ok(Direction4::Down),
+        2 => Ok(Direction4::Left),
+        3 => Ok(Direction4::Up),
+        _ => Err(Error::new(format!("invalid direction digit {}", n))),
+    }
+}
+
+// This time, the number of steps is encoded in the color of the trench.
+// Each hexidecimal code is six digits long. The first five are a five-digit hexadecimal number for the number of steps.
+// The last hexidecimal digit is the direction where: 0 = right, 1 = down, 2 = left, 3 = up.
+// Examples:
+// #70c710 = R 461937
+// #0dc571 = D 56407
+// #5713f0 = R 356671
+// Find the number of cubic meters of dirt that the digger will dig.
+fn lava_dig_plan_alternate(input: &[&str]) -> Result<u64> {
+    // Now the original flood fill is unfeasible with the number of trenches, but the polygon
+    // only needs the corners, and Pick's theorem counts the points inside from its area
+    let steps = input
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse_color_step(line).map_err(|err| err.at_line(i + 1)))
+        .collect::<Result<Vec<_>>>()?;
+    let lagoon = dig_lagoon(steps)?;
+
+    // Pick's theorem states that area = interior_points + boundary_points / 2 - 1
+    // Thus interior points = area - boundary_points / 2 + 1
+    // total_points = interior_points + boundary_points = area - boundary_points / 2 + 1 + boundary_points
+    Ok(lagoon.lattice_points())
+}
+
+// We don't care about the first two parts anymore, just the color.
+// The first five hex digits are the steps, and the last is the direction.
+fn parse_color_step(line: &str) -> Result<(Direction4, i64)> {
+    let color = line
+        .split_once("(#")
+        .and_then(|(_, rest)| rest.trim_end().strip_suffix(')'))
+        .filter(|color| color.len() == 6 && color.is_ascii())
+        .ok_or_else(|| Error::new("expected a color like (#70c710)"))?;
+    let steps = i64::from_str_radix(&color[..5], 16)
+        .map_err(|_| Error::new(format!("invalid hex steps {:?}", &color[..5])))?;
+    let dir = u32::from_str_radix(&color[5..], 16)
+        .map_err(|_| Error::new(format!("invalid hex direction {:?}", &color[5..])))?;
+    Ok((direction_from_digit(dir)?, steps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    // This digs out the following (where # is trench)
+    // #######
+    // #.....#
+    // ###...#
+    // ..#...#
+    // ..#...#
+    // ###.###
+    // #...#..
+    // ##..###
+    // .#....#
+    // .######
+    // Then the interior is dug out, so we count the number of #s, which would be 62.
+    // #######
+    // #######
+    // #######
+    // ..#####
+    // ..#####
+    // #######
+    // #####..
+    // #######
+    // .######
+    // .######
+
+    #[test]
+    fn test_dig_plan_lava_sample() {
+        let input = test_input().lines().collect::<Vec<&str>>();
+        assert_eq!(lava_dig_plan(&input), Ok(62));
+    }
+
+    #[test]
+    fn test_dig_plan_lava_alternate_sample() {
+        let input = test_input().lines().collect::<Vec<&str>>();
+        assert_eq!(lava_dig_plan_alternate(&input), Ok(952408144115));
+    }
+
+    #[test]
+    fn test_dig_plan_lava_alternate_simple() {
+        let input = vec![
+            "R 2 (#000020)",
+            "D 2 (#000021)",
+            "L 2 (#000022)",
+            "U 2 (#000023)",
+        ];
+        assert_eq!(lava_dig_plan_alternate(&input), Ok(9));
+    }
+
+    #[test]
+    fn test_dig_plan_invalid() {
+        assert_eq!("R".parse(), Ok(Direction4::Right));
+        assert!("X".parse::<Direction4>().is_err());
+        let err = lava_dig_plan(&["R 6 (#70c710)", "X 5 (#0dc571)"]).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(1)));
+        let err = lava_dig_plan_alternate(&["R 6 (#70c710)", "D 5 (#0dc574)"]).unwrap_err();
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn test_dig_plan_not_a_loop() {
+        let err = lava_dig_plan(&["R 6 (#70c710)", "D 5 (#0dc571)"]).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the dig plan should be a loop, expected the steps to end back at (0, 0), not (6, 5)"
+        );
+        let input = [
+            "R 2 (#0)", "D 2 (#0)", "L 1 (#0)", "U 3 (#0)", "L 1 (#0)", "D 1 (#0)",
+        ];
+        let err = lava_dig_plan(&input).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the dig plan should not cross itself, but does at (1, 0)"
+        );
+    }
+}
@@ -1,20 +1,70 @@
-use crate::{Solution, SolutionPair};
-use grid::Grid;
-use hashbrown::{HashMap, HashSet};
+use crate::etc::error::parse_number;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+use hashbrown::HashMap;
 use rayon::prelude::*;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day19.txt").expect("Day 19 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1 = sum_rating_numbers(&lines);
-    let sol2 = possible_rating_numbers(&lines);
+pub struct Day19;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day19 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Aplenty"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_rating_numbers(lines)?))
+    }
+
+    fn part2(&self, lines: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(possible_rating_numbers(lines)?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day19);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}"#,
+    Some("19114"),
+    Some("167409079868000"),
+)];
+
 #[derive(Debug)]
 struct Part {
     x: usize,
@@ -39,38 +89,19 @@ type Workflows = HashMap<String, Vec<Rule>>;
 // All parts start at the workflow labeled "in".
 // They are then routed through the workflows, following the rules based on their ratings, until they are accepted or rejected.
 // Find the sum of all the ratings for all the parts that are accepted.
-fn sum_rating_numbers(input: &[&str]) -> usize {
+fn sum_rating_numbers(input: &[&str]) -> Result<usize> {
     let mut part_list: Vec<Part> = Vec::new();
 
     let input_sections: Vec<&[&str]> = input.split(|line| line.is_empty()).collect();
-    let workflows = parse_workflows(input_sections[0]);
-    let part_lines = input_sections[1];
-
-    // Parse parts
-    for line in part_lines {
-        let mut x = 0;
-        let mut m = 0;
-        let mut a = 0;
-        let mut s = 0;
-
-        for part in line
-            .trim_start_matches('{')
-            .trim_end_matches('}')
-            .split(',')
-        {
-            let mut parts = part.split('=');
-            let category = parts.next().unwrap().trim();
-            let value = parts.next().unwrap().trim().parse::<usize>().unwrap();
-            match category {
-                "x" => x = value,
-                "m" => m = value,
-                "a" => a = value,
-                "s" => s = value,
-                _ => unreachable!(),
-            }
-        }
-
-        part_list.push(Part { x, m, a, s });
+    let workflows = parse_workflows(input_sections[0])?;
+    let part_lines = input_sections
+        .get(1)
+        .ok_or_else(|| Error::new("expected a blank line between the workflows and the parts"))?;
+
+    // Parse parts, which start after the workflows and the blank line
+    let first_part_line = input_sections[0].len() + 2;
+    for (i, line) in part_lines.iter().enumerate() {
+        part_list.push(parse_part(line).map_err(|err| err.at_line(first_part_line + i))?);
     }
 
     // println!("{:?}", workflows);
@@ -100,9 +131,9 @@ fn sum_rating_numbers(input: &[&str]) -> usize {
                     })
                 {
                     if dest == "R" {
-                        return 0;
+                        return Ok(0);
                     } else if dest == "A" {
-                        return part.x + part.m + part.a + part.s;
+                        return Ok(part.x + part.m + part.a + part.s);
                     } else {
                         current_label = dest;
                     }
@@ -110,42 +141,63 @@ fn sum_rating_numbers(input: &[&str]) -> usize {
             }
 
             // A matching rule will always be found, because the last rule has no condition
-            // so its condition is set to x > 0, which is always true
-            unreachable!();
+            // so its condition is set to x > 0, which is always true.
+            // So the loop only ends if a part is sent to a workflow that doesn't exist.
+            Err(Error::new(format!("no workflow named {:?}", current_label)))
         })
         .sum()
 }
 
-fn parse_workflows(workflow_lines: &[&str]) -> Workflows {
+// A part looks like {x=787,m=2655,a=1222,s=2876}
+fn parse_part(line: &str) -> Result<Part> {
+    let mut x = 0;
+    let mut m = 0;
+    let mut a = 0;
+    let mut s = 0;
+
+    for part in line
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .split(',')
+    {
+        let (category, value) = part
+            .split_once('=')
+            .ok_or_else(|| Error::new(format!("expected a rating like x=787, found {:?}", part)))?;
+        let value = parse_number::<usize>(value)?;
+        match category.trim() {
+            "x" => x = value,
+            "m" => m = value,
+            "a" => a = value,
+            "s" => s = value,
+            _ => return Err(Error::new(format!("invalid category {:?}", category))),
+        }
+    }
+
+    Ok(Part { x, m, a, s })
+}
+
+fn parse_workflows(workflow_lines: &[&str]) -> Result<Workflows> {
     let mut workflows: Workflows = HashMap::new();
 
-    for line in workflow_lines {
-        let mut parts = line.split('{');
-        let label = parts.next().unwrap().trim().to_string();
-        let mut rules_str = parts
-            .next()
-            .unwrap()
+    for (i, line) in workflow_lines.iter().enumerate() {
+        let (label, rules_str) = line.split_once('{').ok_or_else(|| {
+            Error::new("expected a workflow like px{a<2006:qkq,rfg}").at_line(i + 1)
+        })?;
+        let label = label.trim().to_string();
+        let mut rules_str = rules_str
             .trim_end_matches('}')
             .split(',')
             .collect::<Vec<_>>();
 
-        // Handle the last rule separately
-        let last_rule_str = rules_str.pop().unwrap();
+        // Handle the last rule separately, split always gives at least one rule
+        let last_rule_str = rules_str.pop().unwrap_or_default();
         let last_dest = last_rule_str.trim().to_string();
 
         // Now, iterate over the remaining rules
         let mut rules: Vec<Rule> = rules_str
             .iter()
-            .map(|rule_str| {
-                let mut parts = rule_str.split(':');
-                let condition = parts.next().unwrap().trim();
-                let category = condition.chars().next().unwrap();
-                let operator = condition.chars().nth(1).unwrap();
-                let value = condition[2..].parse::<usize>().unwrap();
-                let dest = parts.next().unwrap().trim();
-                (category, operator, value, dest.to_string())
-            })
-            .collect();
+            .map(|rule_str| parse_rule(rule_str).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<_>>()?;
 
         // Add the last rule (default destination), which has no condition.
         // Give it a condition of x > 0, which is always true so it will always be matched.
@@ -154,17 +206,38 @@ fn parse_workflows(workflow_lines: &[&str]) -> Workflows {
         workflows.insert(label, rules);
     }
 
-    workflows
+    Ok(workflows)
+}
+
+// A rule looks like a<2006:qkq, a category, an operator, a value and a destination
+fn parse_rule(rule_str: &str) -> Result<Rule> {
+    let (condition, dest) = rule_str.split_once(':').ok_or_else(|| {
+        Error::new(format!(
+            "expected a rule like a<2006:qkq, found {:?}",
+            rule_str
+        ))
+    })?;
+    let mut chars = condition.trim().chars();
+    let category = chars
+        .next()
+        .filter(|category| "xmas".contains(*category))
+        .ok_or_else(|| Error::new(format!("invalid category in rule {:?}", rule_str)))?;
+    let operator = chars
+        .next()
+        .filter(|operator| *operator == '<' || *operator == '>')
+        .ok_or_else(|| Error::new(format!("invalid operator in rule {:?}", rule_str)))?;
+    let value = parse_number::<usize>(chars.as_str())?;
+    Ok((category, operator, value, dest.trim().to_string()))
 }
 
 // We have the same set of workflows which accept or reject parts.
 // Ignore the parts list.
 // Each of the ratings can have an integer value from 1 to 4000.
 // Find the number of combinations of ratings that are accepted by the workflows.
-fn possible_rating_numbers(input: &[&str]) -> u64 {
+fn possible_rating_numbers(input: &[&str]) -> Result<u64> {
     // This solution works similarly to Day 5 in taking ranges of numbers and splitting them based on rules.
     let input_sections: Vec<&[&str]> = input.split(|line| line.is_empty()).collect();
-    let workflows = parse_workflows(input_sections[0]);
+    let workflows = parse_workflows(input_sections[0])?;
     let mut range_collection: Vec<(RatingRange, &str)> = vec![(RatingRange::new(), "in")];
     let mut accepted_ranges: Vec<RatingRange> = Vec::new();
 
@@ -203,7 +276,7 @@ fn possible_rating_numbers(input: &[&str]) -> u64 {
                         //     destination,
                         //     forward_range.combinations()
                         // );
-                        range_collection.push((forward_range, &destination));
+                        range_collection.push((forward_range, destination));
                     }
                 }
 
@@ -215,13 +288,13 @@ fn possible_rating_numbers(input: &[&str]) -> u64 {
     // println!("Accepted ranges: {:?}", accepted_ranges);
 
     // Count valid combinations in accepted_ranges
-    accepted_ranges
+    Ok(accepted_ranges
         .iter()
         .map(|range| {
             // Calculate the product of the lengths of each range
             range.combinations()
         })
-        .sum()
+        .sum())
 }
 
 // A range in [start, end) interval notation
@@ -323,34 +396,18 @@ mod tests {
     use super::*;
 
     fn test_input() -> &'static str {
-        r#"px{a<2006:qkq,m>2090:A,rfg}
-pv{a>1716:R,A}
-lnx{m>1548:A,A}
-rfg{s<537:gd,x>2440:R,A}
-qs{s>3448:A,lnx}
-qkq{x<1416:A,crn}
-crn{x>2662:A,R}
-in{s<1351:px,qqz}
-qqz{s>2770:qs,m<1801:hdj,R}
-gd{a>3333:R,R}
-hdj{m>838:A,pv}
-
-{x=787,m=2655,a=1222,s=2876}
-{x=1679,m=44,a=2067,s=496}
-{x=2036,m=264,a=79,s=2244}
-{x=2461,m=1339,a=466,s=291}
-{x=2127,m=1623,a=2188,s=1013}"#
+        EXAMPLES[0].input
     }
 
     #[test]
     fn test_sum_rating_numbers_sample() {
         let input = test_input().lines().collect::<Vec<&str>>();
-        assert_eq!(sum_rating_numbers(&input), 19114);
+        assert_eq!(sum_rating_numbers(&input), Ok(19114));
     }
 
     #[test]
     fn test_possible_rating_numbers_sample() {
         let input = test_input().lines().collect::<Vec<&str>>();
-        assert_eq!(possible_rating_numbers(&input), 167409079868000);
+        assert_eq!(possible_rating_numbers(&input), Ok(167409079868000));
     }
 }
@@ -1,19 +1,71 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::math::lcm_all;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use hashbrown::HashMap;
 use itertools::Itertools;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day20.txt").expect("Day 20 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1 = product_low_high_pulses(&lines);
-    let sol2 = min_button_presses(&lines, "rx");
+pub struct Day20;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day20 {
+    // Pressing the button changes the modules' state, so each part runs on its own copy
+    type Input<'a> = System;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        20
+    }
+
+    fn title(&self) -> &'static str {
+        "Pulse Propagation"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let lines: Vec<&str> = input.lines().collect();
+        initialize_system(&lines)
+    }
+
+    fn part1(&self, system: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(product_low_high_pulses(system.clone())))
+    }
+
+    fn part2(&self, system: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(min_button_presses(system.clone(), "rx")?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day20);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[
+    Example::new(
+        r#"broadcaster -> a, b, c
+%a -> b
+%b -> c
+%c -> inv
+&inv -> a"#,
+        Some("32000000"),
+        None,
+    ),
+    Example::new(
+        r#"broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output"#,
+        Some("11687500"),
+        None,
+    ),
+];
+
 // Input is a line-separated of modules that process low and high pulses.
 // They have a optional prefix type, followed by a name, "->", and a comma-separated list of names.
 // Flip-flop modules "%" are either on or off. They start off.
@@ -28,8 +80,7 @@ pub fn solve() -> SolutionPair {
 // After pushing the button, you must wait until all pulses have propagated through the system.
 // Pulses are always processed in the order they are sent.
 // Find the product of the total number of low pulses and high pulses after pressing the button 1000 times.
-fn product_low_high_pulses(input: &[&str]) -> u64 {
-    let mut system = initialize_system(input);
+pub fn product_low_high_pulses(mut system: System) -> u64 {
     let mut total_low = 0;
     let mut total_high = 0;
 
@@ -37,7 +88,7 @@ fn product_low_high_pulses(input: &[&str]) -> u64 {
         // println!("--- {}: button -{:?}-> broadcaster", i + 1, Pulse::Low);
         total_low += 1;
 
-        let (low_pulses, high_pulses) = process_pulses(&mut system, &mut vec![], None);
+        let (low_pulses, high_pulses) = process_pulses(&mut system, &mut [], None);
         // println!("{}: low: {}, high: {}", i + 1, low_pulses, high_pulses);
         total_low += low_pulses;
         total_high += high_pulses;
@@ -63,7 +114,7 @@ enum ModuleKind {
 }
 
 #[derive(Debug, Clone)]
-struct Module {
+pub struct Module {
     module_kind: ModuleKind,
     outputs: Vec<String>,
 }
@@ -75,24 +126,25 @@ struct PulseMessage {
     pulse: Pulse,
 }
 
-type System = HashMap<String, Module>;
+pub type System = HashMap<String, Module>;
 
-fn initialize_system(input: &[&str]) -> System {
+pub fn initialize_system(input: &[&str]) -> Result<System> {
     let mut system: System = HashMap::new();
     let mut inputs: HashMap<String, Vec<String>> = HashMap::new();
 
-    for line in input {
-        let parts: Vec<&str> = line.split(" -> ").collect();
-        let module_def = parts[0];
-        let outputs: Vec<String> = parts[1].split(", ").map(|s| s.to_string()).collect();
-
-        let (module_kind, label) = match module_def.chars().next().unwrap() {
-            '%' => (ModuleKind::FlipFlop(false), module_def[1..].to_string()),
-            '&' => (
-                ModuleKind::Conjunction(HashMap::new()),
-                module_def[1..].to_string(),
-            ),
-            _ => (ModuleKind::Broadcaster, module_def.to_string()),
+    for (i, line) in input.iter().enumerate() {
+        let (module_def, outputs) = line
+            .split_once(" -> ")
+            .filter(|(module_def, _)| !module_def.is_empty())
+            .ok_or_else(|| Error::new("expected \"<module> -> <outputs>\"").at_line(i + 1))?;
+        let outputs: Vec<String> = outputs.split(", ").map(|s| s.to_string()).collect();
+
+        let (module_kind, label) = if let Some(label) = module_def.strip_prefix('%') {
+            (ModuleKind::FlipFlop(false), label.to_string())
+        } else if let Some(label) = module_def.strip_prefix('&') {
+            (ModuleKind::Conjunction(HashMap::new()), label.to_string())
+        } else {
+            (ModuleKind::Broadcaster, module_def.to_string())
         };
 
         system.insert(
@@ -121,12 +173,12 @@ fn initialize_system(input: &[&str]) -> System {
         }
     }
 
-    system
+    Ok(system)
 }
 
 fn process_pulses(
     system: &mut System,
-    input_module_periods: &mut Vec<(String, Option<u64>)>,
+    input_module_periods: &mut [(String, Option<u64>)],
     button_presses: Option<u64>,
 ) -> (u64, u64) {
     let mut low_pulses = 0;
@@ -212,7 +264,7 @@ fn process_pulses(
             .iter_mut()
             .find(|(module, _)| module == &source_module && pulse == Pulse::High)
         {
-            println!("{} -{:?}-> {}", source_module, pulse, target_module);
+            // println!("{} -{:?}-> {}", source_module, pulse, target_module);
             if period.is_none() {
                 *period = button_presses;
             }
@@ -223,7 +275,7 @@ fn process_pulses(
 }
 
 // Find the minimum number of button presses required to send a single low pulse to rx
-fn min_button_presses(input: &[&str], output_module: &str) -> u64 {
+pub fn min_button_presses(mut system: System, output_module: &str) -> Result<u64> {
     // rx is the output of a single conjunction module, which itself takes some number of conjunction modules as inputs
     // &final -> rx
     //  &in1 -> final
@@ -233,24 +285,27 @@ fn min_button_presses(input: &[&str], output_module: &str) -> u64 {
     // This is infeasible to brute force. It will probably be some sort of lcm of the periods of the inputs.
     // (The example is suggestive of cycles, input probably has perfect cycles, ie no need for Chinese Remainder Theorem)
 
-    let mut system: System = initialize_system(input);
-
     // Find the module that goes into rx
     let final_module = system
         .iter()
         .find(|(_, module)| module.outputs.contains(&output_module.to_string()))
-        .unwrap()
+        .ok_or_else(|| Error::new(format!("no module outputs to {}", output_module)))?
         .0
         .clone();
 
     // Then get all of its inputs. Since it's a conjunction, it's in memory as a hashmap
     let inputs = match &system[&final_module].module_kind {
         ModuleKind::Conjunction(memory) => memory.keys().cloned().collect::<Vec<String>>(),
-        _ => unreachable!(),
+        _ => {
+            return Err(Error::new(format!(
+                "expected {} to be a conjunction module",
+                final_module
+            )))
+        }
     };
 
-    println!("Final module: {}", final_module);
-    println!("Inputs: {:?}", inputs);
+    // println!("Final module: {}", final_module);
+    // println!("Inputs: {:?}", inputs);
 
     // Find the period of each input
     let mut input_module_periods = inputs
@@ -264,25 +319,13 @@ fn min_button_presses(input: &[&str], output_module: &str) -> u64 {
         process_pulses(&mut system, &mut input_module_periods, Some(button_presses));
     }
 
-    println!("Input module periods: {:?}", input_module_periods);
+    // println!("Input module periods: {:?}", input_module_periods);
 
-    lcm(&input_module_periods
+    let periods = input_module_periods
         .iter()
         .filter_map(|(_, period)| *period)
-        .collect_vec())
-}
-
-fn lcm(values: &[u64]) -> u64 {
-    values.iter().fold(1, |acc, &x| acc * x / gcd(acc, x))
-}
-
-fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while b != 0 {
-        let t = b;
-        b = a % b;
-        a = t;
-    }
-    a
+        .collect_vec();
+    lcm_all(&periods).ok_or_else(|| Error::new("the button presses needed overflow a u64"))
 }
 
 #[cfg(test)]
@@ -290,28 +333,20 @@ mod tests {
     use super::*;
 
     fn test_input() -> &'static str {
-        r#"broadcaster -> a, b, c
-%a -> b
-%b -> c
-%c -> inv
-&inv -> a"#
+        EXAMPLES[0].input
     }
 
     fn test_input_2() -> &'static str {
-        r#"broadcaster -> a
-%a -> inv, con
-&inv -> b
-%b -> con
-&con -> output"#
+        EXAMPLES[1].input
     }
 
     #[test]
     fn test_product_low_high_pulses() {
         let input1 = test_input().lines().collect::<Vec<&str>>();
-        let sol1 = product_low_high_pulses(&input1);
+        let sol1 = product_low_high_pulses(initialize_system(&input1).unwrap());
         assert_eq!(sol1, 32000000);
         let input2 = test_input_2().lines().collect::<Vec<&str>>();
-        let sol2 = product_low_high_pulses(&input2);
+        let sol2 = product_low_high_pulses(initialize_system(&input2).unwrap());
         assert_eq!(sol2, 11687500);
     }
 }
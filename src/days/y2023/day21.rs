@@ -0,0 +1,449 @@
+use crate::etc::grid::{Grid, Pos};
+use crate::etc::interpolation::Polynomial;
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
+use itertools::Itertools;
+use pathfinding::prelude::bfs_reach;
+use std::hash::Hash;
+
+///////////////////////////////////////////////////////////////////////////////
+
+// Part 1 counts the plots reachable in `steps` steps, 64 for the real input
+pub struct Day21 {
+    pub steps: usize,
+}
+
+impl Puzzle for Day21 {
+    type Input<'a> = Grid<char>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        21
+    }
+
+    fn title(&self) -> &'static str {
+        "Step Counter"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        parse_garden(input)
+    }
+
+    fn part1(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(num_garden_plots(grid, self.steps)))
+    }
+
+    fn part2(&self, grid: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(num_garden_plots_infinite_grid(
+            grid, 26501365,
+        )?))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day21 { steps: 64 });
+
+// The example from the puzzle description, which only counts plots for 6 steps.
+// Part 2 can't be checked on it, as it's not shaped like a real input (see below).
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+..........."#,
+    Some("16"),
+    None,
+)
+.with_solver(&Day21 { steps: 6 })];
+
+// The input is a 2d grid of the starting position S, garden plots ., and rocks #.
+// The elf can move up, down, left, or right onto a garden plot.
+// We need to find how many garden plots the elf can reach in 64 moves.
+// This is not just distance, consider the starting position, which is always reachable in 2, 4, 6, etc. steps.
+// Or consider any tiles adjacent to the starting position, which is reachable in 1, 3, 5, etc. steps.
+fn num_garden_plots(grid: &Grid<char>, max_steps: usize) -> u64 {
+    // If a tile is reachable in k steps, then it is reachable in k+2 steps by moving to the adjacent tile and then back.
+    // Thus even number distance tiles up to 64 are reachable.
+
+    // Start with the starting position S
+    // count the number of tiles we've seen
+    // We can use BFS, with a successor function of two steps away in each direction and tile not seen before
+    // two steps away is (-2,0), (-1,-1), (0,-2), (1,-1), (2,0), (1,1), (0,2), (-1,1)
+    // ie the immediate diagonals and the cardinals two tiles away
+    // Though we also have to check if each of those neighbors can be reached in two steps
+    // For any of the directions, see if there is a rock on that tile or a previously visited tile
+    // For the cardinal directions, see if there is a rock between the current and the cardinal tile
+    // For the diagonal directions, see if there are two rocks on both of the cardinal tiles between the current and the diagonal tile
+
+    reachable_garden_plots(grid, max_steps)
+}
+
+// The garden has to be a rectangle with a starting position S somewhere in it
+fn parse_garden(input: &str) -> Result<Grid<char>> {
+    let grid = Grid::parse_chars(input)?;
+    if grid.position(|&c| c == 'S').is_none() {
+        return Err(Error::new("no starting position S found"));
+    }
+    Ok(grid)
+}
+
+fn find_start_pos(grid: &Grid<char>, c: char) -> Pos {
+    grid.position(|&cell| cell == c).unwrap()
+}
+
+// The input is a 2d grid of the starting position S, garden plots ., and rocks #.
+// The elf can move up, down, left, or right onto a garden plot.
+// We need to find how many garden plots the elf can reach in 64 moves.
+// This is not just distance, consider the starting position, which is always reachable in 2, 4, 6, etc. steps.
+// Or consider any tiles adjacent to the starting position, which is reachable in 1, 3, 5, etc. steps.
+
+// Now imagine the n x n grid is copied infinitely in all directions.
+// Find number of reachable garden plots for a much larger number.
+fn num_garden_plots_infinite_grid(grid: &Grid<char>, max_steps: usize) -> Result<u64> {
+    // Need to note that input is a square and that the row/col of the starting position is empty.
+    // Also the outside edge of the grid is empty as well. Thus we can conclude the following:
+    // Say we have some reachable tile in the initial grid.
+    // The cost of going to the equivalent tile in the adjacent grid is equal to the side length of the grid.
+    // That is, the number of steps for a given tile is linear in the side length of the grid.
+    // And so the number of tiles reachable in k steps is quadratic because area squares with side length.
+    // Let f(s) be the number of tiles reachable in s steps (ie something like num_garden_plots).
+    // If n is the side length of the grid, we can construct this quadratic polynomial
+    // by interpolating from f(s), f(s+n), f(s+2n).
+
+    let grid_size = grid.rows();
+    let length_to_edge = (grid_size - 1) / 2;
+
+    // The interpolation below only holds for gardens shaped like the puzzle input,
+    // and for step counts of the form k + nl
+    if grid.cols() != grid_size {
+        return Err(Error::new(format!(
+            "the garden should be square, not {}x{}",
+            grid_size,
+            grid.cols()
+        )));
+    }
+    if grid_size.is_multiple_of(2) || find_start_pos(grid, 'S') != (length_to_edge, length_to_edge)
+    {
+        return Err(Error::new(
+            "the starting position S should be in the centre of the garden",
+        ));
+    }
+    if max_steps < length_to_edge || !(max_steps - length_to_edge).is_multiple_of(grid_size) {
+        return Err(Error::new(format!(
+            "the number of steps should be {} plus a multiple of {}, not {}",
+            length_to_edge, grid_size, max_steps
+        )));
+    }
+
+    // ! hardcoded solution
+    let expanded_grid = create_expanded_grid(grid, 7);
+
+    // Define f = reachable_garden_plots
+    // Note v = very_large_number factors into k = starting to edge = (grid_size-1)/2 and l = grid_size
+    // Since v = k + nl, we can write f(v) = f(k+nl)
+    // We can thus find f(v) by interpolating the quadratic from f(k), f(k+l), f(k+2l)
+    let val1 = reachable_garden_plots(&expanded_grid, length_to_edge);
+    let val2 = reachable_garden_plots(&expanded_grid, length_to_edge + grid_size);
+    let val3 = reachable_garden_plots(&expanded_grid, length_to_edge + 2 * grid_size);
+    // So now interpolate from these three values to find f(v), as a polynomial in n
+
+    // Exact, so no rounding creeps in for a target as large as the puzzle's
+    let quadratic = Polynomial::newton(&[val1 as i64, val2 as i64, val3 as i64])?;
+
+    let target_step = (max_steps - length_to_edge) / grid_size;
+    plots_from_fit(&quadratic, target_step)
+}
+
+// The fitted quadratic at n, which for a garden like the input is a whole number of plots
+fn plots_from_fit(quadratic: &Polynomial, n: usize) -> Result<u64> {
+    let plots = quadratic.eval(n as i64)?;
+    plots
+        .to_i64()
+        .and_then(|plots| u64::try_from(plots).ok())
+        .ok_or_else(|| {
+            Error::new(format!(
+                "the fit {} gives {} after {} gardens, which isn't a number of plots",
+                quadratic, plots, n
+            ))
+        })
+}
+
+fn create_expanded_grid(original_grid: &Grid<char>, copies: usize) -> Grid<char> {
+    let rows = original_grid.rows() * copies;
+    let cols = original_grid.cols() * copies;
+
+    // The garden repeats forever, so the expanded grid is a window onto it around the
+    // original copy in the middle, which is the only copy that keeps its starting position
+    let top = (copies / 2 * original_grid.rows()) as isize;
+    let left = (copies / 2 * original_grid.cols()) as isize;
+    let mut expanded_grid = Grid::from_fn(rows, cols, |(i, j)| {
+        let pos = (i as isize - top, j as isize - left);
+        match original_grid.get_signed(pos) {
+            Some(&c) => c,
+            None => match original_grid.get_wrapping(pos) {
+                'S' => '.',
+                &c => c,
+            },
+        }
+    });
+
+    // Place 'S' at the center of the entire expanded grid
+    let center = rows / 2;
+    expanded_grid[(center, center)] = 'S';
+
+    expanded_grid
+}
+
+fn reachable_garden_plots(grid: &Grid<char>, max_steps: usize) -> u64 {
+    let start_pos = find_start_pos(grid, 'S');
+
+    // bfs_reach guarantees that we will only visit each tile once
+    let seen = bfs_reach(
+        SeenPosition {
+            pos: start_pos,
+            steps: 0,
+        },
+        |&pos| successors(grid, pos, max_steps), // This will be the same but using modulo
+    )
+    .collect_vec();
+
+    // have to filter out equal position SeenPositions (ie steps might be different but .pos is same)
+    // since we defined equality for SeenPosition to only care about .pos, we can use unique()
+    let seen = seen.into_iter().unique().collect_vec();
+
+    // remove the starting position from seen if max_steps is odd
+    let seen = if max_steps % 2 == 1 {
+        seen.into_iter()
+            .filter(|&pos| pos.pos != start_pos)
+            .collect_vec()
+    } else {
+        seen
+    };
+
+    seen.len() as u64
+}
+
+#[derive(Clone, Copy, Debug)]
+struct SeenPosition {
+    pos: Pos,
+    steps: usize,
+}
+
+impl Hash for SeenPosition {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.pos.hash(state);
+    }
+}
+
+// two seen positions are equal if their pos is equal, don't care about steps
+impl PartialEq for SeenPosition {
+    fn eq(&self, other: &Self) -> bool {
+        self.pos == other.pos
+    }
+}
+
+impl Eq for SeenPosition {}
+
+fn successors(grid: &Grid<char>, seen_pos: SeenPosition, max_steps: usize) -> Vec<SeenPosition> {
+    let mut next_positions = Vec::new();
+    if seen_pos.steps >= max_steps {
+        return next_positions;
+    }
+
+    let (i, j) = seen_pos.pos;
+
+    if max_steps % 2 == 1 && seen_pos.steps == 0 {
+        // At the first step, if max_steps is odd, we can only move to the adjacent tiles,
+        // then we can do the normal thing of moving two steps away
+        for pos in grid.neighbors4((i, j)) {
+            let new_pos = SeenPosition {
+                pos,
+                steps: seen_pos.steps + 1,
+            };
+
+            // Check if the position is a garden plot and is reachable in one step
+            if grid[new_pos.pos] == '.' {
+                next_positions.push(new_pos);
+            }
+        }
+
+        return next_positions;
+    }
+
+    let directions = [
+        (-2, 0),
+        (-1, -1),
+        (0, -2),
+        (1, -1),
+        (2, 0),
+        (1, 1),
+        (0, 2),
+        (-1, 1),
+    ];
+
+    for (di, dj) in directions.iter() {
+        let new_steps = seen_pos.steps + 2;
+
+        if let Some(pos) = grid.step((i, j), (*di, *dj)) {
+            let new_pos = SeenPosition {
+                pos,
+                steps: new_steps,
+            };
+
+            // Check if the position is a garden plot and is reachable in two steps
+            if grid[new_pos.pos] == '.' && is_reachable(grid, (i, j), (*di, *dj)) {
+                next_positions.push(new_pos);
+            }
+        }
+    }
+
+    next_positions
+}
+
+fn is_reachable(grid: &Grid<char>, (i, j): Pos, (di, dj): (isize, isize)) -> bool {
+    // Check for cardinal directions
+    if di.abs() == 2 || dj.abs() == 2 {
+        let mid_i = i as isize + di / 2;
+        let mid_j = j as isize + dj / 2;
+        return grid[(mid_i as usize, mid_j as usize)] != '#';
+    }
+
+    // Check for diagonal directions
+    if di.abs() == 1 && dj.abs() == 1 {
+        return grid[(i, (j as isize + dj) as usize)] != '#'
+            || grid[((i as isize + di) as usize, j)] != '#';
+    }
+
+    true
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_grid() -> Grid<char> {
+        parse_garden(test_input()).unwrap()
+    }
+
+    fn test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    fn test_input_2() -> &'static str {
+        r#".....
+.#.#.
+..S..
+.#.#.
+....."#
+    }
+
+    #[test]
+    fn test_num_garden_plots_example() {
+        // let sol1 = num_garden_plots(&test_grid(), 2);
+        // assert_eq!(sol1, 4);
+        let sol1 = num_garden_plots(&test_grid(), 3);
+        assert_eq!(sol1, 6);
+        // let sol1 = num_garden_plots(&test_grid(), 6);
+        // assert_eq!(sol1, 16);
+    }
+
+    #[test]
+    fn test_expanded_grid() {
+        let input = Grid::parse_chars(test_input_2()).unwrap();
+        let expanded_grid = create_expanded_grid(&input, 5);
+        assert_eq!(expanded_grid.rows(), 25);
+        assert_eq!(expanded_grid.iter().filter(|&&c| c == 'S').count(), 1);
+        assert_eq!(find_start_pos(&expanded_grid, 'S'), (12, 12));
+
+        // Only the middle copy keeps its S
+        assert_eq!(
+            create_expanded_grid(&input, 3).to_string(),
+            r#"...............
+.#.#..#.#..#.#.
+...............
+.#.#..#.#..#.#.
+...............
+...............
+.#.#..#.#..#.#.
+.......S.......
+.#.#..#.#..#.#.
+...............
+...............
+.#.#..#.#..#.#.
+...............
+.#.#..#.#..#.#.
+..............."#
+        );
+    }
+
+    #[test]
+    fn test_reachable_garden_plots_infinite_grid() {
+        // The example garden doesn't have the input's empty rows and columns through S, so it
+        // can't be interpolated, but a big enough window onto it can still be walked directly
+        let expanded_grid = create_expanded_grid(&test_grid(), 11);
+        let sol1 = reachable_garden_plots(&expanded_grid, 2);
+        assert_eq!(sol1, 4);
+        let sol1 = reachable_garden_plots(&expanded_grid, 6);
+        assert_eq!(sol1, 16);
+        let sol1 = reachable_garden_plots(&expanded_grid, 10);
+        assert_eq!(sol1, 50);
+        let sol1 = reachable_garden_plots(&expanded_grid, 50);
+        assert_eq!(sol1, 1594);
+        // let sol1 = reachable_garden_plots(&expanded_grid, 100);
+        // assert_eq!(sol1, 6536);
+    }
+
+    #[test]
+    fn test_num_garden_plots_infinite_grid() {
+        // The quadratic goes through the three values it was fitted to
+        let expanded_grid = create_expanded_grid(&test_grid(), 7);
+        assert_eq!(
+            num_garden_plots_infinite_grid(&test_grid(), 5 + 2 * 11),
+            Ok(reachable_garden_plots(&expanded_grid, 5 + 2 * 11))
+        );
+
+        let err = num_garden_plots_infinite_grid(&test_grid(), 26501365).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the number of steps should be 5 plus a multiple of 11, not 26501365"
+        );
+        let err = num_garden_plots_infinite_grid(&test_grid(), 2).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the number of steps should be 5 plus a multiple of 11, not 2"
+        );
+        let off_centre = parse_garden("S..\n...\n...").unwrap();
+        let err = num_garden_plots_infinite_grid(&off_centre, 1).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the starting position S should be in the centre of the garden"
+        );
+        let err = num_garden_plots_infinite_grid(&parse_garden("S..").unwrap(), 1).unwrap_err();
+        assert_eq!(err.message, "the garden should be square, not 1x3");
+    }
+
+    #[test]
+    fn test_plots_from_fit() {
+        let quadratic = Polynomial::newton(&[1, 4, 9]).unwrap();
+        assert_eq!(plots_from_fit(&quadratic, 10), Ok(121));
+        let halves = Polynomial::lagrange(&[(0, 0), (2, 1)]).unwrap();
+        let err = plots_from_fit(&halves, 1).unwrap_err();
+        assert_eq!(
+            err.message,
+            "the fit 1/2x gives 1/2 after 1 gardens, which isn't a number of plots"
+        );
+        let negative = Polynomial::newton(&[0, -1]).unwrap();
+        assert!(plots_from_fit(&negative, 1).is_err());
+    }
+}
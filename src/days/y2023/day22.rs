@@ -1,19 +1,61 @@
-use crate::{Solution, SolutionPair};
+use crate::etc::error::{parse_lines, parse_number};
+use crate::etc::{Error, Example, Puzzle, Result, Solution};
+use crate::register;
 use hashbrown::{HashMap, HashSet};
 use std::collections::VecDeque;
-use std::fs::read_to_string;
 
 ///////////////////////////////////////////////////////////////////////////////
 
-pub fn solve() -> SolutionPair {
-    let input = read_to_string("input/day22.txt").expect("Day 22 input file should be present");
-    let lines: Vec<&str> = input.lines().collect();
-    let sol1 = safe_bricks(&lines);
-    let sol2 = sum_bricks_falling(&lines);
+pub struct Day22;
 
-    (Solution::from(sol1), Solution::from(sol2))
+impl Puzzle for Day22 {
+    type Input<'a> = Vec<Brick>;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        22
+    }
+
+    fn title(&self) -> &'static str {
+        "Sand Slabs"
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let lines: Vec<&str> = input.lines().collect();
+        sort_bricks(&lines)
+    }
+
+    fn part1(&self, bricks: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(safe_bricks(bricks)))
+    }
+
+    fn part2(&self, bricks: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::from(sum_bricks_falling(bricks)))
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
+register!(Day22);
+
+// The examples from the puzzle description, with their answers
+const EXAMPLES: &[Example] = &[Example::new(
+    r#"1,0,1~1,2,1
+0,0,2~2,0,2
+0,2,3~2,2,3
+0,0,4~0,2,4
+2,0,5~2,2,5
+0,1,6~2,1,6
+1,1,8~1,1,9"#,
+    Some("5"),
+    Some("7"),
+)];
+
 // The input is a line separated list of bricks, given by two x,y,z coordinates separated by tilde.
 // These coordinates represent the ends of the brick. 2,2,2~2,2,2 is a 1x1x1 brick, while 0,0,10~1,0,10 is a 2x1x1 brick.
 // 0,0,1~0,0,10 is a 1x1x10 brick. The ground is at z=0, so the lowest z value a brick can have is 1.
@@ -21,7 +63,7 @@ pub fn solve() -> SolutionPair {
 // Bricks never rotate despite physics, so they will always fall straight down.
 // A brick is safe to disintegrate if, after removing it, no other bricks will fall.
 // Find the number of bricks that are safe to disintegrate.
-fn safe_bricks(input: &[&str]) -> usize {
+fn safe_bricks(sorted_bricks: &[Brick]) -> usize {
     // First, we need to simulate the bricks falling.
     // We do this by getting lowest z value for each brick and then sorting by that.
     // Then we iterate through the bricks, and for each brick, find the highest z value of the bricks below it.
@@ -29,12 +71,10 @@ fn safe_bricks(input: &[&str]) -> usize {
     // Every brick is formatted such that x1 <= x2, y1 <= y2, and z1 <= z2.
     // So we just sort on z1, and then for each brick, find the highest z2 of the bricks below it.
 
-    let sorted_bricks = sort_bricks(input);
-
     // Also need to consider if there are no bricks below it, then move to z=1.
     // How to represent movement? Just change the z1 and z2 values.
 
-    let fallen_bricks = fall_bricks(&sorted_bricks);
+    let fallen_bricks = fall_bricks(sorted_bricks);
 
     // After that, we can find safe bricks by checking if, for all bricks directly above it
     // there exists at least two bricks that are not directly above it.
@@ -44,7 +84,7 @@ fn safe_bricks(input: &[&str]) -> usize {
 
     bricks_directly_above
         .iter()
-        .map(|(&brick, directly_above)| {
+        .map(|(_, directly_above)| {
             directly_above.iter().all(|&other| {
                 let directly_below = bricks_directly_below.get(&other).unwrap();
                 directly_below.len() >= 2
@@ -54,16 +94,16 @@ fn safe_bricks(input: &[&str]) -> usize {
         .count()
 }
 
-fn sort_bricks(input: &[&str]) -> Vec<Brick> {
-    let mut bricks: Vec<Brick> = input.iter().map(|&s| Brick::from_str(s)).collect();
+fn sort_bricks(input: &[&str]) -> Result<Vec<Brick>> {
+    let mut bricks: Vec<Brick> = parse_lines(input, Brick::from_str)?;
     bricks.sort_by_key(|b| b.z1);
-    bricks
+    Ok(bricks)
 }
 
 fn fall_bricks(sorted_bricks: &[Brick]) -> Vec<Brick> {
     let mut fallen_bricks = sorted_bricks.to_vec();
     for i in 0..sorted_bricks.len() {
-        let mut new_brick = sorted_bricks[i].clone();
+        let mut new_brick = sorted_bricks[i];
         let max_z2 = fallen_bricks
             .iter()
             .filter(|&other| other.is_below(&new_brick))
@@ -88,7 +128,7 @@ fn bricks_directly_above_and_below(
         let directly_above: Vec<Brick> = fallen_bricks
             .iter()
             .filter(|&other| brick.is_directly_below(other))
-            .map(|&other| other)
+            .copied()
             .collect();
         bricks_directly_above.insert(*brick, directly_above.clone());
         for &other in directly_above.iter() {
@@ -104,9 +144,8 @@ fn bricks_directly_above_and_below(
 }
 
 // For each brick, find how many other bricks would fall if it were removed.
-fn sum_bricks_falling(input: &[&str]) -> usize {
-    let sorted_bricks = sort_bricks(input);
-    let fallen_bricks = fall_bricks(&sorted_bricks);
+fn sum_bricks_falling(sorted_bricks: &[Brick]) -> usize {
+    let fallen_bricks = fall_bricks(sorted_bricks);
     let (bricks_directly_above, bricks_directly_below) =
         bricks_directly_above_and_below(&fallen_bricks);
 
@@ -146,7 +185,7 @@ type BricksDirectlyAbove = HashMap<Brick, Vec<Brick>>;
 type BricksDirectlyBelow = HashMap<Brick, Vec<Brick>>;
 
 #[derive(Clone, Copy, Eq, Hash, PartialEq)]
-struct Brick {
+pub struct Brick {
     x1: usize,
     y1: usize,
     z1: usize,
@@ -177,20 +216,30 @@ impl Brick {
         }
     }
 
-    fn from_str(s: &str) -> Self {
-        let mut coords = s.split('~');
-        let mut start_coords = coords.next().unwrap().split(',');
-        let mut end_coords = coords.next().unwrap().split(',');
-
-        let x1 = start_coords.next().unwrap().parse().unwrap();
-        let y1 = start_coords.next().unwrap().parse().unwrap();
-        let z1 = start_coords.next().unwrap().parse().unwrap();
-
-        let x2 = end_coords.next().unwrap().parse().unwrap();
-        let y2 = end_coords.next().unwrap().parse().unwrap();
-        let z2 = end_coords.next().unwrap().parse().unwrap();
+    fn from_str(s: &str) -> Result<Self> {
+        let (start, end) = s
+            .split_once('~')
+            .ok_or_else(|| Error::new("expected a brick like 1,0,1~1,2,1"))?;
+        let [x1, y1, z1] = Self::parse_coords(start)?;
+        let [x2, y2, z2] = Self::parse_coords(end)?;
+
+        // Everything else assumes the start corner is the lowest one
+        if x1 > x2 || y1 > y2 || z1 > z2 {
+            return Err(Error::new(
+                "expected the first corner of the brick to be the lowest",
+            ));
+        }
+        Ok(Brick::new(x1, y1, z1, x2, y2, z2))
+    }
 
-        Brick::new(x1, y1, z1, x2, y2, z2)
+    fn parse_coords(s: &str) -> Result<[usize; 3]> {
+        let coords = s
+            .split(',')
+            .map(parse_number::<usize>)
+            .collect::<Result<Vec<_>>>()?;
+        coords.try_into().map_err(|coords: Vec<usize>| {
+            Error::new(format!("expected 3 coordinates, found {}", coords.len()))
+        })
     }
 
     fn is_below(&self, other: &Self) -> bool {
@@ -219,26 +268,32 @@ mod tests {
     use super::*;
 
     fn test_input() -> &'static str {
-        r#"1,0,1~1,2,1
-0,0,2~2,0,2
-0,2,3~2,2,3
-0,0,4~0,2,4
-2,0,5~2,2,5
-0,1,6~2,1,6
-1,1,8~1,1,9"#
+        EXAMPLES[0].input
     }
 
     #[test]
     fn test_safe_bricks() {
         let lines: Vec<&str> = test_input().lines().collect();
-        let sol1 = safe_bricks(&lines);
-        assert_eq!(sol1, 5);
+        let bricks = sort_bricks(&lines).unwrap();
+        assert_eq!(safe_bricks(&bricks), 5);
     }
 
     #[test]
     fn test_sum_bricks_falling() {
         let lines: Vec<&str> = test_input().lines().collect();
-        let sol2 = sum_bricks_falling(&lines);
-        assert_eq!(sol2, 7);
+        let bricks = sort_bricks(&lines).unwrap();
+        assert_eq!(sum_bricks_falling(&bricks), 7);
+    }
+
+    #[test]
+    fn test_brick_from_str() {
+        assert_eq!(
+            Brick::from_str("1,0,1~1,2,1"),
+            Ok(Brick::new(1, 0, 1, 1, 2, 1))
+        );
+        assert!(Brick::from_str("1,0,1~1,2").is_err());
+        assert!(Brick::from_str("1,0,2~1,2,1").is_err());
+        let err = sort_bricks(&["1,0,1~1,2,1", "0,0,2-2,0,2"]).unwrap_err();
+        assert_eq!(err.line, Some(2));
     }
 }
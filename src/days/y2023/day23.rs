@@ -0,0 +1,72 @@
+use crate::etc::{Example, Part, Puzzle, Result, Solution};
+use crate::register;
+
+///////////////////////////////////////////////////////////////////////////////
+
+pub struct Day23;
+
+impl Puzzle for Day23 {
+    type Input<'a> = &'a str;
+
+    fn year(&self) -> u16 {
+        2023
+    }
+
+    fn day(&self) -> u8 {
+        23
+    }
+
+    fn title(&self) -> &'static str {
+        "A Long Walk"
+    }
+
+    // Remove once the parts are solved
+    fn is_implemented(&self, _part: Part) -> bool {
+        false
+    }
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input)
+    }
+
+    fn part1(&self, _input: &Self::Input<'_>) -> Result<Solution> {
+        // Your solution here...
+        Ok(Solution::Unsolved)
+    }
+
+    fn part2(&self, _input: &Self::Input<'_>) -> Result<Solution> {
+        Ok(Solution::Unsolved)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
+}
+
+register!(Day23);
+
+// The example from the puzzle description. Fill in each part's answer as it's solved, so that
+// `--example` checks it.
+const EXAMPLES: &[Example] = &[Example::new(r#""#, None, None)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_input() -> &'static str {
+        EXAMPLES[0].input
+    }
+
+    // Replace Unsolved with the example's answers as each part is solved
+    #[test]
+    fn test_part1() {
+        let input = Day23.parse(test_input()).unwrap();
+        assert_eq!(Day23.part1(&input), Ok(Solution::Unsolved));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = Day23.parse(test_input()).unwrap();
+        assert_eq!(Day23.part2(&input), Ok(Solution::Unsolved));
+    }
+}
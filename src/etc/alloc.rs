@@ -0,0 +1,172 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::{Display, Formatter};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering::Relaxed};
+
+// A global allocator that counts what the solvers allocate, for the runner's --memory option.
+// It only counts once `enable` is called, and costs an atomic load per allocation until then.
+// The counts are for the whole process, so they only belong to one day when days run one at
+// a time (threads a day starts itself, eg with rayon, are counted with it).
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+// Signed, since memory allocated before counting started can be freed after
+static LIVE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    BYTES.fetch_add(size as u64, Relaxed);
+    let live = LIVE.fetch_add(size as i64, Relaxed) + size as i64;
+    PEAK.fetch_max(live, Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && ENABLED.load(Relaxed) {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Relaxed) {
+            LIVE.fetch_sub(layout.size() as i64, Relaxed);
+        }
+    }
+
+    // Growing or shrinking counts as a new allocation of the new size, as a copy would be
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && ENABLED.load(Relaxed) {
+            LIVE.fetch_sub(layout.size() as i64, Relaxed);
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+// Start counting. Only has an effect if CountingAllocator is the global allocator.
+pub fn enable() {
+    ENABLED.store(true, Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Relaxed)
+}
+
+// What a step allocated: the number of allocations, their total size, and the most it had
+// allocated at once, over what was already allocated when it started
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    pub bytes: u64,
+    pub peak: u64,
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} allocation{}, {} allocated, {} peak",
+            self.allocations,
+            if self.allocations == 1 { "" } else { "s" },
+            format_bytes(self.bytes),
+            format_bytes(self.peak)
+        )
+    }
+}
+
+// Run `f`, along with what it allocated when counting is enabled.
+// Steps can be measured inside one another, eg each part of a run as well as the whole run.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !is_enabled() {
+        return (f(), None);
+    }
+    let (allocations, bytes) = (ALLOCATIONS.load(Relaxed), BYTES.load(Relaxed));
+    let live = LIVE.load(Relaxed);
+    // Track the peak from here, then put back any higher one an outer step had seen
+    let outer_peak = PEAK.swap(live, Relaxed);
+
+    let result = f();
+
+    let peak = PEAK.fetch_max(outer_peak, Relaxed);
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+        bytes: BYTES.load(Relaxed) - bytes,
+        peak: (peak - live).max(0) as u64,
+    };
+    (result, Some(usage))
+}
+
+// A size in bytes, eg "512 B" or "1.5 MiB"
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+// The library's own tests count too, so that `measure` can be checked
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        enable();
+        // Other tests allocate at the same time, so these are only lower bounds
+        let (_, outer) = measure(|| {
+            let (kept, inner) = measure(|| vec![1u8; 4096]);
+            let inner = inner.unwrap();
+            assert!(inner.allocations >= 1);
+            assert!(inner.bytes >= 4096 && inner.peak >= 4096);
+            let (_, dropped) = measure(|| vec![1u8; 8192].len());
+            assert!(dropped.unwrap().peak >= 8192);
+            kept
+        });
+        let outer = outer.unwrap();
+        assert!(outer.allocations >= 2);
+        assert!(outer.bytes >= 4096 + 8192);
+        assert!(outer.peak >= 4096 + 8192);
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        let usage = Usage {
+            allocations: 3,
+            bytes: 2048,
+            peak: 100,
+        };
+        assert_eq!(
+            usage.to_string(),
+            "3 allocations, 2.0 KiB allocated, 100 B peak"
+        );
+    }
+}
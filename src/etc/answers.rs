@@ -0,0 +1,150 @@
+use crate::etc::solution::Solution;
+use crate::etc::solver::Part;
+use hashbrown::HashMap;
+use std::fmt::{Display, Formatter};
+use std::fs::read_to_string;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Confirmed answers for the real puzzle inputs, so refactors can be checked against them.
+// They are kept in answers/<year>.toml with a table per day, eg
+//
+// [day01]
+// part1 = 54630
+// part2 = "abc"
+//
+// Answers can be integers or strings, and either part can be left out until it's confirmed.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u8, Part), String>,
+}
+
+impl Answers {
+    pub fn path(year: u16) -> PathBuf {
+        PathBuf::from(format!("answers/{}.toml", year))
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        read_to_string(path)?.parse()
+    }
+
+    // With no confirmed answers there's nothing to verify against
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    // Compare a solution against the confirmed answer for that day and part
+    pub fn check(&self, day: u8, part: Part, solution: &Solution) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected == solution.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.to_string()),
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let table: toml::Table = s.parse().map_err(|err| invalid(format!("{}", err)))?;
+
+        let mut answers = HashMap::new();
+        for (key, parts) in &table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse::<u8>().ok())
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| invalid(format!("expected [dayNN] tables, found [{}]", key)))?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| invalid(format!("{} should be a table of parts", key)))?;
+
+            for (name, answer) in parts {
+                let part = match name.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(invalid(format!("unknown part {}.{}", key, name))),
+                };
+                let answer = match answer {
+                    toml::Value::Integer(answer) => answer.to_string(),
+                    toml::Value::String(answer) => answer.clone(),
+                    _ => {
+                        return Err(invalid(format!(
+                            "{}.{} should be an integer or a string",
+                            key, name
+                        )))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Answers { answers })
+    }
+}
+
+// How a part's solution compares to its confirmed answer.
+// A failure holds the expected answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            Verdict::Missing => write!(f, "MISSING"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let answers: Answers = r#"
+[day01]
+part1 = 142
+part2 = "281"
+
+[day25]
+part1 = 54
+"#
+        .parse()
+        .unwrap();
+
+        assert!(!answers.is_empty());
+        assert!("# only comments\n".parse::<Answers>().unwrap().is_empty());
+
+        let check = |day, part, solution: u64| answers.check(day, part, &solution.into());
+        assert_eq!(check(1, Part::One, 142), Verdict::Pass);
+        assert_eq!(check(1, Part::Two, 281), Verdict::Pass);
+        assert_eq!(check(1, Part::Two, 280), Verdict::Fail("281".to_string()));
+        assert_eq!(check(25, Part::Two, 0), Verdict::Missing);
+        assert_eq!(check(2, Part::One, 8), Verdict::Missing);
+        assert_eq!(
+            answers.check(1, Part::One, &Solution::Unsolved),
+            Verdict::Fail("142".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invalid_answers() {
+        assert!("[day1]\npart3 = 1".parse::<Answers>().is_err());
+        assert!("[day26]\npart1 = 1".parse::<Answers>().is_err());
+        assert!("[first]\npart1 = 1".parse::<Answers>().is_err());
+        assert!("[day01]\npart1 = 1.5".parse::<Answers>().is_err());
+        assert!("[day01\n".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,539 @@
+use crate::etc::report::Format;
+use crate::etc::solver::Part;
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: aoc-2023-jmm [bench | verify | fetch | submit | new | watch] [days...] [options]
+
+With no days, runs the latest day of the year that has a solution.
+
+Commands:
+  bench               Run the days many times and report timing statistics
+  verify              Check the answers against answers/<year>.toml (every day by default)
+  fetch               Download the inputs to input/<year> (the latest day by default)
+  submit              Send one day's answer for a part, eg submit --day 5 --part 1
+  new                 Start a new day from templates/day.rs.tmpl, eg new 5
+  watch               Re-run one day whenever its input, example or source changes, eg watch 5
+
+Days:
+  5                   A single day
+  1-10                A range of days
+  1,5,9               A list of days, which can include ranges, eg 1-3,7
+  -all                Every day
+
+Options:
+  --year <year>       The event to run (default: the latest year with solutions)
+  --skip <days>       Leave out some days, eg -all --skip 21
+  --input <path>      Read the input from a file instead, or - for stdin (repeatable)
+  --part <1|2>        Only run one part
+  --example           Run on the puzzles' examples and check their answers (every day by default)
+  --memory            Count what each day and part allocates, and the peak memory in use
+  --format <format>   Print text (default), json, csv or markdown
+  --jobs <n>          Run up to n days at once (default 1)
+  --timeout <secs>    Give up on a day after this many seconds, leaving it running until
+                      the runner exits
+  --list              List the days and which parts are implemented
+  -h, --help          Show this help
+
+Bench options:
+  --runs <n>          Timed runs per day (default 100)
+  --warmup <n>        Untimed runs beforehand (default 10)
+  --save <file>       Save the medians as a baseline
+  --compare <file>    Compare the medians against a saved baseline
+
+Verify options:
+  --answers <path>    Check against another answers file
+
+Fetch options:
+  --force             Download the input again even if it's already saved
+
+Submit options:
+  --day <day>         The day to submit (or give it as a day argument)
+  --answer <answer>   Send this answer instead of running the solver
+
+New options:
+  --title <title>     The puzzle's title (default: Day <day>)
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench,
+    Verify,
+    Fetch,
+    Submit,
+    New,
+    Watch,
+    List,
+    Help,
+}
+
+// Everything given on the command line. Options that don't apply to the command are rejected
+// while parsing, so eg `runs` is only ever changed for bench.
+#[derive(Debug, PartialEq)]
+pub struct Args {
+    pub command: Command,
+    // None means the latest year with solutions
+    pub year: Option<u16>,
+    // Sorted, without duplicates or skipped days. Empty when running means the latest day.
+    pub days: Vec<u8>,
+    pub inputs: Vec<String>,
+    pub part: Option<Part>,
+    pub format: Format,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub runs: usize,
+    pub warmup: usize,
+    pub save: Option<PathBuf>,
+    pub compare: Option<PathBuf>,
+    pub answers: Option<PathBuf>,
+    pub force: bool,
+    pub answer: Option<String>,
+    pub title: Option<String>,
+    pub example: bool,
+    pub memory: bool,
+}
+
+impl Default for Args {
+    fn default() -> Self {
+        Args {
+            command: Command::Run,
+            year: None,
+            days: Vec::new(),
+            inputs: Vec::new(),
+            part: None,
+            format: Format::Text,
+            jobs: 1,
+            timeout: None,
+            runs: 100,
+            warmup: 10,
+            save: None,
+            compare: None,
+            answers: None,
+            force: false,
+            answer: None,
+            title: None,
+            example: false,
+            memory: false,
+        }
+    }
+}
+
+// The commands that solve days, and so take inputs and a part
+const SOLVING: &[Command] = &[
+    Command::Run,
+    Command::Bench,
+    Command::Submit,
+    Command::Watch,
+];
+// The commands that run a batch of days and report on them together
+const RUNNING: &[Command] = &[Command::Run, Command::Bench];
+
+// Parse the arguments after the program name, with an error message for anything invalid
+pub fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut parsed = Args::default();
+    let mut args = args.iter().map(String::as_str).peekable();
+    parsed.command = match args.peek() {
+        Some(&"bench") => Command::Bench,
+        Some(&"verify") => Command::Verify,
+        Some(&"fetch") => Command::Fetch,
+        Some(&"submit") => Command::Submit,
+        Some(&"new") => Command::New,
+        Some(&"watch") => Command::Watch,
+        _ => Command::Run,
+    };
+    if parsed.command != Command::Run {
+        args.next();
+    }
+
+    let mut days = BTreeSet::new();
+    let mut skip = BTreeSet::new();
+    let given_command = parsed.command;
+    // The options for running a batch of days, which some runs have no use for
+    let mut run_options = Vec::new();
+    while let Some(arg) = args.next() {
+        let only_for = |commands: &[Command], name: &str| {
+            if commands.contains(&given_command) {
+                Ok(())
+            } else {
+                Err(format!("{} can only be used with {}", arg, name))
+            }
+        };
+
+        match arg {
+            "-h" | "--help" => {
+                parsed.command = Command::Help;
+                return Ok(parsed);
+            }
+            "--list" | "-list" => parsed.command = Command::List,
+            "--year" => {
+                let year = value(&mut args, arg, "a year")?;
+                parsed.year = Some(year.parse().ok().filter(|&year| year >= 2015).ok_or_else(
+                    || format!("--year must be an event year from 2015 on, not {}", year),
+                )?);
+            }
+            "-all" | "--all" => days.extend(1..=25),
+            "--skip" => skip.extend(parse_days(value(&mut args, arg, "days")?)?),
+            "--input" => {
+                run_options.push(arg);
+                only_for(SOLVING, "a run, bench, submit or watch")?;
+                parsed
+                    .inputs
+                    .push(value(&mut args, arg, "a path")?.to_string());
+            }
+            "--part" => {
+                run_options.push(arg);
+                only_for(SOLVING, "a run, bench, submit or watch")?;
+                parsed.part = match value(&mut args, arg, "a part")? {
+                    "1" => Some(Part::One),
+                    "2" => Some(Part::Two),
+                    other => return Err(format!("--part must be 1 or 2, not {}", other)),
+                }
+            }
+            "--example" => {
+                only_for(&[Command::Run], "a plain run")?;
+                parsed.example = true;
+            }
+            "--memory" => {
+                run_options.push(arg);
+                only_for(&[Command::Run], "a plain run")?;
+                parsed.memory = true;
+            }
+            "--format" => {
+                run_options.push(arg);
+                only_for(RUNNING, "a run or bench")?;
+                parsed.format = value(&mut args, arg, "a format")?.parse()?;
+            }
+            "--jobs" => {
+                run_options.push(arg);
+                only_for(RUNNING, "a run or bench")?;
+                parsed.jobs = parse_count(value(&mut args, arg, "a number")?, arg, 1)?;
+            }
+            "--timeout" => {
+                run_options.push(arg);
+                only_for(RUNNING, "a run or bench")?;
+                let secs = value(&mut args, arg, "a number of seconds")?;
+                parsed.timeout = Some(
+                    secs.parse()
+                        .ok()
+                        .and_then(|secs| Duration::try_from_secs_f64(secs).ok())
+                        .filter(|timeout| !timeout.is_zero())
+                        .ok_or_else(|| {
+                            format!(
+                                "--timeout must be a positive number of seconds, not {}",
+                                secs
+                            )
+                        })?,
+                );
+            }
+            "--runs" => {
+                only_for(&[Command::Bench], "bench")?;
+                parsed.runs = parse_count(value(&mut args, arg, "a number")?, arg, 1)?;
+            }
+            "--warmup" => {
+                only_for(&[Command::Bench], "bench")?;
+                parsed.warmup = parse_count(value(&mut args, arg, "a number")?, arg, 0)?;
+            }
+            "--save" => {
+                only_for(&[Command::Bench], "bench")?;
+                parsed.save = Some(value(&mut args, arg, "a file")?.into());
+            }
+            "--compare" => {
+                only_for(&[Command::Bench], "bench")?;
+                parsed.compare = Some(value(&mut args, arg, "a file")?.into());
+            }
+            "--answers" => {
+                only_for(&[Command::Verify], "verify")?;
+                parsed.answers = Some(value(&mut args, arg, "a path")?.into());
+            }
+            "--force" => {
+                only_for(&[Command::Fetch], "fetch")?;
+                parsed.force = true;
+            }
+            "--day" => {
+                only_for(&[Command::Submit], "submit")?;
+                days.extend(parse_days(value(&mut args, arg, "a day")?)?);
+            }
+            "--answer" => {
+                only_for(&[Command::Submit], "submit")?;
+                parsed.answer = Some(value(&mut args, arg, "an answer")?.to_string());
+            }
+            "--title" => {
+                only_for(&[Command::New], "new")?;
+                parsed.title = Some(value(&mut args, arg, "a title")?.to_string());
+            }
+            _ if arg.starts_with('-') => {
+                return Err(format!("unknown option {}", arg));
+            }
+            _ => days.extend(parse_days(arg)?),
+        }
+    }
+
+    // Verifying, checking examples, or skipping days without choosing any, starts from every day
+    let given = !days.is_empty();
+    if !given && (parsed.command == Command::Verify || parsed.example || !skip.is_empty()) {
+        days.extend(1..=25);
+    }
+    parsed.days = days.difference(&skip).copied().collect();
+
+    if parsed.days.is_empty() && (given || !skip.is_empty()) {
+        return Err("every day given was skipped, so there is nothing to run".to_string());
+    }
+    if parsed.days.is_empty() && parsed.command == Command::Bench {
+        return Err("bench needs some days to run, eg bench -all".to_string());
+    }
+    // Without days, the runner picks the latest day and prints both parts on its own input.
+    // The examples are checked one at a time against the answers they declare.
+    if parsed.command == Command::Run {
+        let (ignored, name): (&[&str], _) = if parsed.days.is_empty() {
+            (
+                &[
+                    "--input",
+                    "--part",
+                    "--format",
+                    "--memory",
+                    "--jobs",
+                    "--timeout",
+                ],
+                "some days to run, eg 5",
+            )
+        } else if parsed.example {
+            (
+                &["--input", "--format", "--memory", "--jobs", "--timeout"],
+                "the real inputs, not --example",
+            )
+        } else {
+            (&[], "")
+        };
+        if let Some(option) = run_options.iter().find(|option| ignored.contains(option)) {
+            return Err(format!("{} can only be used with {}", option, name));
+        }
+    }
+    if parsed.command == Command::Submit && (parsed.days.len() != 1 || parsed.part.is_none()) {
+        return Err("submit needs one day and a part, eg submit --day 5 --part 1".to_string());
+    }
+    if parsed.command == Command::New && parsed.days.len() != 1 {
+        return Err("new needs one day, eg new 5".to_string());
+    }
+    // Allocations are counted for the whole process, so days have to run one at a time, and a
+    // day that timed out would carry on allocating during the days after it
+    if parsed.memory && parsed.jobs > 1 {
+        return Err(
+            "--memory can't be used with --jobs, days have to run one at a time".to_string(),
+        );
+    }
+    if parsed.memory && parsed.timeout.is_some() {
+        return Err(
+            "--memory can't be used with --timeout, a day left running would be counted with the next"
+                .to_string(),
+        );
+    }
+    if parsed.command == Command::Watch && parsed.days.len() != 1 {
+        return Err("watch needs one day, eg watch 5".to_string());
+    }
+    Ok(parsed)
+}
+
+// Parse a day, a range like 1-10, or a list of either like 1-3,7
+pub fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    let invalid = || {
+        format!(
+            "invalid days \"{}\": expected a day from 1 to 25, a range like 1-10 or a list like 1,5,9",
+            spec
+        )
+    };
+    let day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(invalid)
+    };
+
+    let mut days = Vec::new();
+    for item in spec.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (day(start)?, day(end)?);
+                if start > end {
+                    return Err(format!(
+                        "invalid range \"{}\": {} comes after {}",
+                        item, start, end
+                    ));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(day(item)?),
+        }
+    }
+    Ok(days)
+}
+
+// The value following an option, eg the path after --input
+fn value<'a>(
+    args: &mut impl Iterator<Item = &'a str>,
+    option: &str,
+    expected: &str,
+) -> Result<&'a str, String> {
+    args.next()
+        .ok_or_else(|| format!("{} requires {}", option, expected))
+}
+
+fn parse_count(value: &str, option: &str, min: usize) -> Result<usize, String> {
+    value
+        .parse()
+        .ok()
+        .filter(|&count| count >= min)
+        .ok_or_else(|| {
+            format!(
+                "{} must be a whole number of at least {}, not {}",
+                option, min, value
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Args, String> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        parse_args(&args)
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("5"), Ok(vec![5]));
+        assert_eq!(parse_days("1-4"), Ok(vec![1, 2, 3, 4]));
+        assert_eq!(parse_days("1,5,9"), Ok(vec![1, 5, 9]));
+        assert_eq!(parse_days("1-3,7"), Ok(vec![1, 2, 3, 7]));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("x").is_err());
+        assert!(parse_days("1,").is_err());
+        assert!(parse_days("1-").is_err());
+        assert_eq!(
+            parse_days("10-3"),
+            Err("invalid range \"10-3\": 10 comes after 3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(parse(""), Ok(Args::default()));
+        let args = parse("3 1-2,3 --part 2 --jobs 4 --format csv --input a.txt --input -").unwrap();
+        assert_eq!(args.days, [1, 2, 3]);
+        assert_eq!(args.part, Some(Part::Two));
+        assert_eq!(args.jobs, 4);
+        assert_eq!(args.format, Format::Csv);
+        assert_eq!(args.inputs, ["a.txt", "-"]);
+
+        let args = parse("bench -all --skip 21,23-25 --runs 5 --timeout 1.5").unwrap();
+        assert_eq!(args.command, Command::Bench);
+        assert_eq!(args.days, (1..=20).chain([22]).collect::<Vec<_>>());
+        assert_eq!(args.runs, 5);
+        assert_eq!(args.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(parse("1 --year 2022").unwrap().year, Some(2022));
+
+        assert_eq!(parse("verify").unwrap().days.len(), 25);
+        assert_eq!(parse("--example").unwrap().days.len(), 25);
+        assert_eq!(parse("3 --example").unwrap().days, [3]);
+        assert!(parse("1-5 --memory").unwrap().memory);
+        assert_eq!(parse("--skip 1").unwrap().days.len(), 24);
+        assert_eq!(parse("--list").unwrap().command, Command::List);
+        let args = parse("fetch 1-3 --force").unwrap();
+        assert_eq!(
+            (args.command, args.days.len(), args.force),
+            (Command::Fetch, 3, true)
+        );
+        let args = parse("new 5 --title Cube").unwrap();
+        assert_eq!((args.days, args.title), (vec![5], Some("Cube".to_string())));
+        let args = parse("submit --day 5 --part 1 --answer 42").unwrap();
+        assert_eq!((args.days, args.answer), (vec![5], Some("42".to_string())));
+        let args = parse("watch 5 --part 2").unwrap();
+        assert_eq!((args.command, args.days), (Command::Watch, vec![5]));
+        assert_eq!(parse("5 --help --bogus").unwrap().command, Command::Help);
+    }
+
+    #[test]
+    fn test_parse_args_errors() {
+        let err = |args| parse(args).unwrap_err();
+        assert_eq!(err("--bogus"), "unknown option --bogus");
+        assert_eq!(err("1 --part"), "--part requires a part");
+        assert_eq!(err("1 --part 3"), "--part must be 1 or 2, not 3");
+        assert_eq!(err("1 --runs 5"), "--runs can only be used with bench");
+        assert_eq!(
+            err("1 --answers a.toml"),
+            "--answers can only be used with verify"
+        );
+        assert_eq!(err("1 --force"), "--force can only be used with fetch");
+        assert_eq!(
+            err("bench 1 --example"),
+            "--example can only be used with a plain run"
+        );
+        assert!(err("submit --day 5").starts_with("submit needs one day and a part"));
+        assert!(err("submit 1-2 --part 1").starts_with("submit needs one day and a part"));
+        assert!(err("new").starts_with("new needs one day"));
+        assert!(err("watch 1-2").starts_with("watch needs one day"));
+        assert_eq!(
+            err("bench 1 --memory"),
+            "--memory can only be used with a plain run"
+        );
+        assert!(err("1 --memory --jobs 2").starts_with("--memory can't be used with --jobs"));
+        assert!(err("1 --timeout 5 --memory").starts_with("--memory can't be used with --timeout"));
+        assert_eq!(err("1 --title x"), "--title can only be used with new");
+        assert_eq!(
+            err("verify --part 1"),
+            "--part can only be used with a run, bench, submit or watch"
+        );
+        assert_eq!(
+            err("fetch 5 --input a.txt"),
+            "--input can only be used with a run, bench, submit or watch"
+        );
+        assert_eq!(
+            err("new 5 --format csv"),
+            "--format can only be used with a run or bench"
+        );
+        assert_eq!(
+            err("verify --jobs 2"),
+            "--jobs can only be used with a run or bench"
+        );
+        assert_eq!(
+            err("watch 5 --timeout 1"),
+            "--timeout can only be used with a run or bench"
+        );
+        assert!(err("bench").starts_with("bench needs some days"));
+        let latest = "can only be used with some days to run, eg 5";
+        assert_eq!(err("--input a.txt"), format!("--input {}", latest));
+        assert_eq!(err("--input -"), format!("--input {}", latest));
+        assert_eq!(err("--part 1"), format!("--part {}", latest));
+        assert_eq!(err("--format json"), format!("--format {}", latest));
+        assert_eq!(err("--memory"), format!("--memory {}", latest));
+        assert_eq!(err("--jobs 2"), format!("--jobs {}", latest));
+        assert_eq!(err("--timeout 5"), format!("--timeout {}", latest));
+        let example = "can only be used with the real inputs, not --example";
+        assert_eq!(
+            err("1 --example --format json"),
+            format!("--format {}", example)
+        );
+        assert_eq!(
+            err("--example --input a.txt"),
+            format!("--input {}", example)
+        );
+        assert_eq!(err("--example --memory"), format!("--memory {}", example));
+        assert_eq!(err("--example --jobs 2"), format!("--jobs {}", example));
+        assert_eq!(
+            err("--example --timeout 1"),
+            format!("--timeout {}", example)
+        );
+        assert_eq!(parse("--example --part 2").unwrap().part, Some(Part::Two));
+        assert!(err("5 --skip 5").starts_with("every day given was skipped"));
+        assert!(err("1 --jobs 0").starts_with("--jobs must be a whole number of at least 1"));
+        assert!(err("1 --timeout -1").starts_with("--timeout must be a positive number"));
+        assert!(err("1 --year 23").starts_with("--year must be an event year from 2015 on"));
+        assert!(err("1 --format yaml").starts_with("unknown format yaml"));
+        assert!(err("day5").starts_with("invalid days \"day5\""));
+    }
+}
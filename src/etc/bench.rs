@@ -0,0 +1,256 @@
+use crate::etc::error::Result;
+use crate::etc::solver::{Part, Run, Solver};
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+// Summary statistics over repeated timings of one step
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+}
+
+impl Stats {
+    // None if there are no samples to summarize
+    pub fn new(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        // Sample standard deviation, which is zero for a single run
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        // Nearest-rank percentile, so p95 is always one of the samples
+        let p95 = sorted[(n * 95).div_ceil(100) - 1];
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p95,
+        })
+    }
+}
+
+// The result of running a solver many times. The answers are taken from the last run,
+// and parts that weren't selected have no stats.
+#[derive(Clone, Debug)]
+pub struct Bench {
+    pub run: Run,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+    pub total: Stats,
+}
+
+impl Bench {
+    // Each step that has stats, by the name used in baselines
+    pub fn steps(&self) -> Vec<(&'static str, Stats)> {
+        [
+            ("parse", Some(self.parse)),
+            ("part1", self.part1),
+            ("part2", self.part2),
+            ("total", Some(self.total)),
+        ]
+        .into_iter()
+        .filter_map(|(step, stats)| Some((step, stats?)))
+        .collect()
+    }
+
+    // The answers with each step's median time, to report a bench like a single run
+    pub fn median_run(&self) -> Run {
+        Run {
+            parse_time: self.parse.median,
+            part1_time: self.part1.map(|stats| stats.median),
+            part2_time: self.part2.map(|stats| stats.median),
+            ..self.run.clone()
+        }
+    }
+}
+
+// Run the solver `warmup` times without recording anything, to let caches and the rayon
+// thread pool settle, then `runs` times (at least once) for the stats
+pub fn bench(
+    solver: &dyn Solver,
+    input: &str,
+    part: Option<Part>,
+    warmup: usize,
+    runs: usize,
+) -> Result<Bench> {
+    for _ in 0..warmup {
+        solver.run(input, part)?;
+    }
+
+    let samples = (0..runs.max(1))
+        .map(|_| solver.run(input, part))
+        .collect::<Result<Vec<Run>>>()?;
+
+    let stats = |time: fn(&Run) -> Option<Duration>| {
+        Stats::new(&samples.iter().filter_map(time).collect::<Vec<_>>())
+    };
+    Ok(Bench {
+        parse: stats(|run| Some(run.parse_time)).unwrap(),
+        part1: stats(|run| run.part1_time),
+        part2: stats(|run| run.part2_time),
+        total: stats(|run| Some(run.total_time())).unwrap(),
+        run: samples.last().unwrap().clone(),
+    })
+}
+
+// Median times from an earlier bench, keyed by the year, the run's label and the step.
+// The labels only name the day, eg "Day 01", so the year keeps a baseline saved for one event
+// from matching the same days of another.
+// Saved as one tab separated "year, label, step, nanoseconds" line per step.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    medians: BTreeMap<(u16, String, String), Duration>,
+}
+
+impl Baseline {
+    pub fn insert(&mut self, year: u16, label: &str, bench: &Bench) {
+        for (step, stats) in bench.steps() {
+            self.medians
+                .insert((year, label.to_string(), step.to_string()), stats.median);
+        }
+    }
+
+    pub fn get(&self, year: u16, label: &str, step: &str) -> Option<Duration> {
+        self.medians
+            .get(&(year, label.to_string(), step.to_string()))
+            .copied()
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        read_to_string(path)?.parse()
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write(path, self.to_string())
+    }
+}
+
+impl std::str::FromStr for Baseline {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let mut baseline = Baseline::default();
+        for (i, line) in s.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "line {}: expected \"<year>\\t<label>\\t<step>\\t<nanoseconds>\"",
+                        i + 1
+                    ),
+                )
+            };
+            let [year, label, step, nanos] = line.split('\t').collect::<Vec<_>>()[..] else {
+                return Err(invalid());
+            };
+            let year: u16 = year.parse().map_err(|_| invalid())?;
+            let nanos: u64 = nanos.parse().map_err(|_| invalid())?;
+            baseline.medians.insert(
+                (year, label.to_string(), step.to_string()),
+                Duration::from_nanos(nanos),
+            );
+        }
+        Ok(baseline)
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((year, label, step), median) in &self.medians {
+            writeln!(f, "{}\t{}\t{}\t{}", year, label, step, median.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+// The change from `old` to `new` as a percentage of `old`, eg -50.0 for twice as fast.
+// None when `old` is zero, which no change can be a percentage of.
+pub fn percent_change(old: Duration, new: Duration) -> Option<f64> {
+    if old.is_zero() {
+        return None;
+    }
+    let (old, new) = (old.as_nanos() as f64, new.as_nanos() as f64);
+    Some((new - old) / old * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::solver::find_solver;
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [5, 1, 4, 2, 3].map(Duration::from_millis).to_vec();
+        let stats = Stats::new(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        assert!((stats.stddev.as_secs_f64() - 2.5e-6f64.sqrt()).abs() < 1e-9);
+
+        let even = Stats::new(&samples[..4]).unwrap();
+        assert_eq!(even.median, Duration::from_micros(3000));
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn test_bench() {
+        let solver = find_solver(2023, 1).unwrap();
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        let bench = bench(solver, input, Some(Part::One), 1, 3).unwrap();
+        assert!(bench.part1.is_some());
+        assert!(bench.part2.is_none());
+        let steps: Vec<&str> = bench.steps().iter().map(|(step, _)| *step).collect();
+        assert_eq!(steps, ["parse", "part1", "total"]);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let solver = find_solver(2023, 1).unwrap();
+        let bench = bench(solver, "1abc2", None, 0, 1).unwrap();
+        let mut baseline = Baseline::default();
+        baseline.insert(2023, "Day 01 (a b.txt)", &bench);
+
+        let parsed: Baseline = baseline.to_string().parse().unwrap();
+        assert_eq!(parsed, baseline);
+        assert_eq!(
+            parsed.get(2023, "Day 01 (a b.txt)", "total"),
+            Some(bench.total.median)
+        );
+        assert_eq!(parsed.get(2022, "Day 01 (a b.txt)", "total"), None);
+        assert!("Day 01\ttotal\t5".parse::<Baseline>().is_err());
+        assert!("23x\tDay 01\ttotal\t5".parse::<Baseline>().is_err());
+
+        let old = Duration::from_millis(200);
+        assert_eq!(percent_change(old, Duration::from_millis(100)), Some(-50.0));
+        assert_eq!(percent_change(old, Duration::from_millis(250)), Some(25.0));
+        assert_eq!(percent_change(Duration::ZERO, old), None);
+    }
+}
@@ -0,0 +1,56 @@
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+
+// Puzzles unlock at midnight US Eastern time. The event is in December, so that's always
+// EST (UTC-5) rather than daylight time.
+pub fn eastern() -> FixedOffset {
+    FixedOffset::west_opt(5 * 60 * 60).unwrap()
+}
+
+// When the puzzle for a day of the event unlocks
+pub fn unlock_time(year: u16, day: u8) -> DateTime<FixedOffset> {
+    eastern()
+        .with_ymd_and_hms(year as i32, 12, day as u32, 0, 0, 0)
+        .unwrap()
+}
+
+pub fn is_unlocked(year: u16, day: u8, now: DateTime<Utc>) -> bool {
+    now >= unlock_time(year, day)
+}
+
+// The most recent day of the event to have unlocked by `now`, which is 25 once the event is
+// over, or None if it hasn't started yet
+pub fn latest_unlocked_day(year: u16, now: DateTime<Utc>) -> Option<u8> {
+    (1..=25).rev().find(|&day| is_unlocked(year, day, now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(month: u32, day: u32, hour: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, month, day, hour, min, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2023, 1), utc(12, 1, 5, 0));
+        assert_eq!(
+            unlock_time(2023, 25).to_string(),
+            "2023-12-25 00:00:00 -05:00"
+        );
+        assert!(!is_unlocked(2023, 10, utc(12, 10, 4, 59)));
+        assert!(is_unlocked(2023, 10, utc(12, 10, 5, 0)));
+    }
+
+    #[test]
+    fn test_latest_unlocked_day() {
+        assert_eq!(latest_unlocked_day(2023, utc(11, 15, 12, 0)), None);
+        assert_eq!(latest_unlocked_day(2023, utc(12, 1, 4, 59)), None);
+        assert_eq!(latest_unlocked_day(2023, utc(12, 1, 5, 0)), Some(1));
+        // Still the 9th in UTC-5, even though it's already the 10th in UTC
+        assert_eq!(latest_unlocked_day(2023, utc(12, 10, 4, 59)), Some(9));
+        assert_eq!(latest_unlocked_day(2023, utc(12, 31, 12, 0)), Some(25));
+        assert_eq!(latest_unlocked_day(2022, utc(6, 1, 12, 0)), Some(25));
+    }
+}
@@ -0,0 +1,209 @@
+use std::env;
+use std::fs::read_to_string;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+// Advent of Code asks automated tools to identify themselves with a way to reach the author
+pub const USER_AGENT: &str = "github.com/JMMarchan/aoc-2023-jmm";
+
+// Where to find the site and the session token to log in with. The token is the `session`
+// cookie from a logged in browser.
+// Both are read from aoc.toml (kept out of git) and can be overridden with the AOC_SESSION and
+// AOC_BASE_URL environment variables, eg to point the client at a local server.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub base_url: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: BASE_URL.to_string(),
+        }
+    }
+}
+
+impl Config {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut config = match read_to_string(path) {
+            Ok(contents) => contents.parse()?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Config::default(),
+            Err(err) => return Err(err),
+        };
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+}
+
+impl std::str::FromStr for Config {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+        let table: toml::Table = s.parse().map_err(|err| invalid(format!("{}", err)))?;
+
+        let mut config = Config::default();
+        for (key, value) in &table {
+            let value = value
+                .as_str()
+                .ok_or_else(|| invalid(format!("{} should be a string", key)))?
+                .to_string();
+            match key.as_str() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value,
+                _ => return Err(invalid(format!("unknown setting {}", key))),
+            }
+        }
+        Ok(config)
+    }
+}
+
+// A logged in client for the site
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        let session = config
+            .session
+            .clone()
+            .ok_or("no session token, set AOC_SESSION or add `session = \"...\"` to aoc.toml")?;
+        Ok(Client {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            session,
+        })
+    }
+
+    // GET a page, eg "/2023/day/1/input", returning its body
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let request = self.agent.get(&format!("{}{}", self.base_url, path));
+        self.send(
+            path,
+            request
+                .set("Cookie", &format!("session={}", self.session))
+                .call(),
+        )
+    }
+
+    // POST a form, eg an answer to "/2023/day/1/answer", returning the page it leads to
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let request = self.agent.post(&format!("{}{}", self.base_url, path));
+        self.send(
+            path,
+            request
+                .set("Cookie", &format!("session={}", self.session))
+                .send_form(form),
+        )
+    }
+
+    fn send(
+        &self,
+        path: &str,
+        response: Result<ureq::Response, ureq::Error>,
+    ) -> Result<String, String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| format!("could not read {}: {}", path, err)),
+            // The site answers with 400 (or sometimes 500) when the session token isn't valid
+            Err(ureq::Error::Status(400 | 500, _)) => Err(format!(
+                "{} was refused, the session token may be wrong or expired",
+                path
+            )),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(format!("{} failed with HTTP {}", path, status))
+            }
+            // The error already names the URL
+            Err(ureq::Error::Transport(err)) => Err(format!("could not connect, {}", err)),
+        }
+    }
+}
+
+// A stand in for the site, for tests
+#[cfg(test)]
+pub mod stub {
+    use super::Config;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    // What the client sent
+    #[derive(Debug, PartialEq, Eq)]
+    pub struct Request {
+        pub url: String,
+        pub cookie: String,
+        pub user_agent: String,
+        pub body: String,
+    }
+
+    // Answer every request with `status` and `body`, passing on each request to the receiver.
+    // The config points at the server and has a session of "abc".
+    pub fn server(status: u16, body: &'static str) -> (Config, Receiver<Request>) {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let config = Config {
+            session: Some("abc".to_string()),
+            base_url: format!("http://{}", server.server_addr()),
+        };
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let header = |name: &'static str| {
+                    request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv(name))
+                        .map(|header| header.value.to_string())
+                        .unwrap_or_default()
+                };
+                let (cookie, user_agent) = (header("Cookie"), header("User-Agent"));
+                let mut form = String::new();
+                let _ = request.as_reader().read_to_string(&mut form);
+                let _ = sender.send(Request {
+                    url: request.url().to_string(),
+                    cookie,
+                    user_agent,
+                    body: form,
+                });
+                let response = tiny_http::Response::from_string(body).with_status_code(status);
+                let _ = request.respond(response);
+            }
+        });
+        (config, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config() {
+        let config: Config = "session = \"abc\"\nbase_url = \"http://localhost:8080\""
+            .parse()
+            .unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url, "http://localhost:8080");
+
+        let config: Config = "".parse().unwrap();
+        assert_eq!(config, Config::default());
+        assert!("session = 1".parse::<Config>().is_err());
+        assert!("token = \"abc\"".parse::<Config>().is_err());
+        assert!(Client::new(&config).is_err());
+    }
+}
@@ -0,0 +1,873 @@
+// The runner's commands, one function per command, which print their results and exit with an
+// error code when something fails. The binary only parses the arguments and picks one.
+use crate::etc::alloc::Usage;
+use crate::etc::answers::{Answers, Verdict};
+use crate::etc::args::{Args, Command};
+use crate::etc::bench::{bench, percent_change, Baseline, Bench};
+use crate::etc::calendar::{is_unlocked, latest_unlocked_day, unlock_time};
+use crate::etc::client::{Client, Config};
+use crate::etc::fetch::{fetch_input, Fetched};
+use crate::etc::jobs::{run_in_order, Task};
+use crate::etc::report::{render, Format, Outcome, Record};
+use crate::etc::scaffold::new_day;
+use crate::etc::solver::{example_path, find_solver, input_path, source_path};
+use crate::etc::submit::{submit_answer, Feedback, History};
+use crate::etc::watch::{compare, Watcher};
+use crate::etc::{Memory, Part, Run, Solution, Solver};
+use chrono::Utc;
+use hashbrown::HashMap;
+use itertools::Itertools;
+use std::env;
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+// A finished run, along with its stats when benchmarking
+type Finished = (Run, Option<Bench>);
+
+// Where a solver's input comes from. By default each solver reads its own input file,
+// but any number of files (or stdin, given as "-") can be passed with --input instead.
+enum InputSource {
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    fn from_arg(arg: &str) -> Self {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    fn label(&self, solver: &dyn Solver) -> String {
+        match self {
+            InputSource::Default => solver.input_path().display().to_string(),
+            InputSource::File(path) => path.display().to_string(),
+            InputSource::Stdin => "stdin".to_string(),
+        }
+    }
+
+    // Stdin can only be read once, so it is read up front and passed in for every solver.
+    fn read(&self, solver: &dyn Solver, stdin: &str) -> io::Result<String> {
+        match self {
+            InputSource::Default => read_to_string(solver.input_path()),
+            InputSource::File(path) => read_to_string(path),
+            InputSource::Stdin => Ok(stdin.to_string()),
+        }
+    }
+}
+
+// Print an error and exit, for problems with the arguments or the files they name
+pub fn exit_with_error(message: &str) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(2);
+}
+
+pub fn run_latest(year: u16) {
+    // Start from the latest puzzle that has unlocked (at midnight UTC-5), which is the 25th once
+    // the event is over. If that day can't be run or has no solution, try the previous day
+    // until one can, and stop after the 1st.
+    let Some(latest) = latest_unlocked_day(year, Utc::now()) else {
+        println!(
+            "The {} event hasn't started yet, day 1 unlocks at {}",
+            year,
+            unlock_time(year, 1)
+        );
+        return;
+    };
+    println!(
+        "No day argument given. The latest puzzle of {} is day {}, so trying that day...",
+        year, latest
+    );
+
+    for day in (1..=latest).rev() {
+        let next = if day > 1 {
+            ", trying the previous day..."
+        } else {
+            ""
+        };
+        let Some(solver) = find_solver(year, day) else {
+            println!("Day {} has no solver{}", day, next);
+            continue;
+        };
+        let Ok(input) = read_to_string(solver.input_path()) else {
+            println!(
+                "Day {} has no input at {}{}",
+                day,
+                solver.input_path().display(),
+                next
+            );
+            continue;
+        };
+        match solver.run(&input, None) {
+            Ok(run) if run.part1.is_solved() || run.part2.is_solved() => {
+                println!("\n=== Day {:02}: {} ===", day, solver.title());
+                print_run(&run);
+                return;
+            }
+            Ok(_) => println!("Day {} has no solution{}", day, next),
+            Err(err) => println!("Error: {}{}", err, next),
+        }
+    }
+
+    println!(
+        "\nNothing to run: none of days 1 to {} of {} has a solution with an input.",
+        latest, year
+    );
+}
+
+// Describe each day, and which of its parts are implemented
+pub fn list(year: u16) {
+    for day in 1..=25 {
+        let Some(solver) = find_solver(year, day) else {
+            println!("{}/day{:02}: not registered", year, day);
+            continue;
+        };
+        let status = match (
+            solver.is_implemented(Part::One),
+            solver.is_implemented(Part::Two),
+        ) {
+            (true, true) => "both parts",
+            (true, false) => "part 1 only",
+            (false, true) => "part 2 only",
+            (false, false) => "not implemented",
+        };
+        println!("{}: {} ({})", solver.name(), solver.title(), status);
+    }
+}
+
+// Run the given days, once each or many times when benchmarking
+pub fn run(year: u16, args: &Args) {
+    let bench_mode = args.command == Command::Bench;
+    let (part, runs, warmup) = (args.part, args.runs, args.warmup);
+
+    let mut sources: Vec<InputSource> = args
+        .inputs
+        .iter()
+        .map(|input| InputSource::from_arg(input))
+        .collect();
+    if sources.is_empty() {
+        sources.push(InputSource::Default);
+    }
+
+    let mut stdin = String::new();
+    if sources
+        .iter()
+        .any(|source| matches!(source, InputSource::Stdin))
+    {
+        io::stdin()
+            .read_to_string(&mut stdin)
+            .expect("stdin should be readable");
+    }
+
+    // Load the baseline up front, so a bad path is reported before spending time benchmarking
+    let previous = args.compare.as_ref().map(|path| {
+        Baseline::load(path).unwrap_or_else(|err| {
+            exit_with_error(&format!(
+                "could not read baseline {}: {}",
+                path.display(),
+                err
+            ))
+        })
+    });
+    let mut baseline = Baseline::default();
+
+    // Other formats print only the records at the end, so anything else goes to stderr
+    let text = args.format == Format::Text;
+    let message = |message: String| {
+        if text {
+            println!("\n{}", message);
+        } else {
+            eprintln!("{}", message);
+        }
+    };
+    let mut records: Vec<Record> = Vec::new();
+
+    if bench_mode && text {
+        println!(
+            "Benchmarking {} runs per day after {} warm-up runs",
+            runs, warmup
+        );
+    }
+
+    // Runtimes (medians when benchmarking) are kept alongside a note about any parts without an answer
+    // and what the run allocated, when counting allocations
+    let mut runtimes: HashMap<String, (f64, Option<String>, Option<Usage>)> = HashMap::new();
+
+    // Read every input up front, then run the days on as many threads as --jobs allows.
+    // The results still come back in order, along with the details of each run from `queued`.
+    let mut tasks: Vec<Task<crate::Result<Finished>>> = Vec::new();
+    let mut queued = Vec::new();
+    for &day in &args.days {
+        let Some(solver) = find_solver(year, day) else {
+            message(format!("No solver registered for day {}, skipping...", day));
+            continue;
+        };
+
+        for source in &sources {
+            let input = match source.read(solver, &stdin) {
+                Ok(input) => input,
+                Err(err) => {
+                    // A missing input file can usually just be downloaded
+                    let hint = match source {
+                        InputSource::Default if err.kind() == io::ErrorKind::NotFound => {
+                            format!(", fetch it with `fetch {} --year {}`", day, year)
+                        }
+                        _ => String::new(),
+                    };
+                    message(format!(
+                        "Could not read {} for day {}: {}{}",
+                        source.label(solver),
+                        day,
+                        err,
+                        hint
+                    ));
+                    continue;
+                }
+            };
+
+            // Only label the runs by input when the inputs were chosen explicitly
+            let label = match source {
+                InputSource::Default => format!("Day {:02}", day),
+                _ => format!("Day {:02} ({})", day, source.label(solver)),
+            };
+            queued.push((day, solver, label, source.label(solver)));
+
+            tasks.push(Box::new(move || {
+                if bench_mode {
+                    bench(solver, &input, part, warmup, runs)
+                        .map(|bench| (bench.run.clone(), Some(bench)))
+                } else {
+                    solver.run(&input, part).map(|run| (run, None))
+                }
+            }));
+        }
+    }
+
+    let mut queued = queued.into_iter();
+    run_in_order(tasks, args.jobs, args.timeout, |result| {
+        let (day, solver, label, input) = queued.next().unwrap();
+        let record = |outcome| Record {
+            year: solver.year(),
+            day,
+            title: solver.title(),
+            input,
+            outcome,
+        };
+
+        // A bad input or a runaway solver shouldn't stop the rest of the days from running
+        if text {
+            println!("\n=== {}: {} ===", label, solver.title());
+        }
+        let (run, bench) = match result {
+            Some(Ok(result)) => result,
+            Some(Err(err)) => {
+                if text {
+                    println!("  · Error: {}", err);
+                }
+                records.push(record(Outcome::Failed(err)));
+                return;
+            }
+            None => {
+                let timeout = args.timeout.unwrap();
+                if text {
+                    println!("  · TIMED OUT after {}s", timeout.as_secs_f64());
+                }
+                records.push(record(Outcome::TimedOut(timeout)));
+                return;
+            }
+        };
+
+        let note = unsolved_note(&run.part1, &run.part2);
+        match bench {
+            Some(bench) => {
+                if text {
+                    print_bench(&bench, solver.year(), &label, previous.as_ref());
+                }
+                baseline.insert(solver.year(), &label, &bench);
+                runtimes.insert(label, (as_ms(bench.total.median), note, None));
+                records.push(record(Outcome::Ran(bench.median_run())));
+            }
+            None => {
+                if text {
+                    print_run(&run);
+                }
+                let memory = run.memory.map(|memory| memory.total);
+                runtimes.insert(label, (as_ms(run.total_time()), note, memory));
+                records.push(record(Outcome::Ran(run)));
+            }
+        }
+    });
+
+    if let Some(path) = &args.save {
+        match baseline.save(path) {
+            Ok(()) => message(format!("Saved baseline to {}", path.display())),
+            Err(err) => message(format!(
+                "Could not save baseline to {}: {}",
+                path.display(),
+                err
+            )),
+        }
+    }
+
+    // A day that failed or timed out makes the whole run fail, so scripts can tell
+    let count = |kind: fn(&Outcome) -> bool| records.iter().filter(|r| kind(&r.outcome)).count();
+    let failed = count(|outcome| matches!(outcome, Outcome::Failed(_)));
+    let timed_out = count(|outcome| matches!(outcome, Outcome::TimedOut(_)));
+    // Exiting also stops any days that timed out, which are still running in the background
+    let finish = || process::exit(if failed + timed_out > 0 { 1 } else { 0 });
+
+    if !text {
+        print!("{}", render(args.format, &records));
+        finish();
+    }
+
+    if records.is_empty() {
+        println!("\nNo days were run.");
+        finish();
+    }
+    println!(
+        "\n{} ran, {} failed, {} timed out",
+        records.len() - failed - timed_out,
+        failed,
+        timed_out
+    );
+    if runtimes.is_empty() {
+        finish();
+    }
+
+    let total_runtime = runtimes
+        .values()
+        .map(|(runtime, _, _)| runtime)
+        .sum::<f64>();
+    println!("\nTotal runtime: {:.4} ms", total_runtime);
+    println!(
+        "Average runtime: {:.4} ms",
+        total_runtime / runtimes.len() as f64
+    );
+
+    println!("\n=== Sorted Runtimes ===");
+    // Print runtimes in order from fastest to slowest
+    runtimes
+        .iter()
+        .sorted_by(|(_, (a, _, _)), (_, (b, _, _))| a.total_cmp(b))
+        .for_each(|(label, (runtime, note, memory))| {
+            let memory = memory.map(|memory| format!(", {}", memory));
+            match note {
+                Some(note) => println!(
+                    "{}: {:.4} ms{} ({})",
+                    label,
+                    runtime,
+                    memory.unwrap_or_default(),
+                    note
+                ),
+                None => println!("{}: {:.4} ms{}", label, runtime, memory.unwrap_or_default()),
+            }
+        });
+    finish();
+}
+
+// Run the given days, or every day, on their real inputs and compare the answers with the
+// confirmed ones in answers/<year>.toml (or --answers <path>).
+// Exits with an error if any answer doesn't match, so refactors can't quietly change them, or if
+// there are no answers to check at all.
+pub fn verify(year: u16, args: &Args) {
+    let path = args.answers.clone().unwrap_or_else(|| Answers::path(year));
+    let answers = Answers::load(&path).unwrap_or_else(|err| {
+        exit_with_error(&format!(
+            "could not read answers {}: {}",
+            path.display(),
+            err
+        ))
+    });
+    // Every part would be missing, which would pass without checking anything
+    if answers.is_empty() {
+        exit_with_error(&format!(
+            "{} has no confirmed answers yet, add them as they're accepted to verify against",
+            path.display()
+        ));
+    }
+
+    let mut verdicts: Vec<Verdict> = Vec::new();
+    for &day in &args.days {
+        let Some(solver) = find_solver(year, day) else {
+            println!("\nNo solver registered for day {}, skipping...", day);
+            continue;
+        };
+        println!("\n=== Day {:02}: {} ===", day, solver.title());
+
+        // Without an input there is nothing to check against
+        let Ok(input) = read_to_string(solver.input_path()) else {
+            println!(
+                "  · MISSING (no input at {})",
+                solver.input_path().display()
+            );
+            verdicts.extend([Verdict::Missing, Verdict::Missing]);
+            continue;
+        };
+        let run = match solver.run(&input, None) {
+            Ok(run) => run,
+            Err(err) => {
+                println!("  · FAIL ({})", err);
+                verdicts.extend([0, 1].map(|_| Verdict::Fail(err.to_string())));
+                continue;
+            }
+        };
+
+        for (i, (part, solution)) in [(Part::One, &run.part1), (Part::Two, &run.part2)]
+            .into_iter()
+            .enumerate()
+        {
+            let verdict = answers.check(day, part, solution);
+            println!("  · Part {}: {} {}", i + 1, solution, verdict);
+            verdicts.push(verdict);
+        }
+    }
+
+    let count = |kind: fn(&Verdict) -> bool| verdicts.iter().filter(|v| kind(v)).count();
+    let failed = count(|verdict| matches!(verdict, Verdict::Fail(_)));
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(|verdict| *verdict == Verdict::Pass),
+        failed,
+        count(|verdict| *verdict == Verdict::Missing)
+    );
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+// Run the given days, or every day, on the examples from their puzzle descriptions and check
+// the answers the puzzles give for them, eg to try out a new solver before its real input.
+// Exits with an error if any answer doesn't match.
+pub fn examples(year: u16, args: &Args) {
+    let mut verdicts: Vec<Verdict> = Vec::new();
+    for &day in &args.days {
+        let Some(solver) = find_solver(year, day) else {
+            continue;
+        };
+        println!("\n=== Day {:02}: {} ===", day, solver.title());
+        if solver.examples().is_empty() {
+            println!("  · No examples");
+            continue;
+        }
+
+        for (i, example) in solver.examples().iter().enumerate() {
+            // Only the parts with an answer are run, since an example can be meant for one part
+            let parts: Vec<Part> = [Part::One, Part::Two]
+                .into_iter()
+                .filter(|&part| args.part.is_none_or(|only| only == part))
+                .filter(|&part| example.answer(part).is_some())
+                .collect();
+            if parts.is_empty() {
+                println!("  · Example {}: no answers to check", i + 1);
+            }
+            for part in parts {
+                let level = if part == Part::One { 1 } else { 2 };
+                let answer = example.answer(part).unwrap();
+                let run = match example.run(solver, Some(part)) {
+                    Ok(run) => run,
+                    Err(err) => {
+                        println!("  · Example {}, part {}: FAIL ({})", i + 1, level, err);
+                        verdicts.push(Verdict::Fail(answer.to_string()));
+                        continue;
+                    }
+                };
+                let solution = if part == Part::One {
+                    run.part1
+                } else {
+                    run.part2
+                };
+                let verdict = if solution.to_string() == answer {
+                    Verdict::Pass
+                } else {
+                    Verdict::Fail(answer.to_string())
+                };
+                println!(
+                    "  · Example {}, part {}: {} {}",
+                    i + 1,
+                    level,
+                    solution,
+                    verdict
+                );
+                verdicts.push(verdict);
+            }
+        }
+    }
+
+    let failed = verdicts
+        .iter()
+        .filter(|verdict| matches!(verdict, Verdict::Fail(_)))
+        .count();
+    println!("\n{} passed, {} failed", verdicts.len() - failed, failed);
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+// Download the inputs for the given days, or the latest day that has unlocked, into input/<year>.
+// The session token and site come from aoc.toml or the AOC_SESSION and AOC_BASE_URL variables.
+pub fn fetch(year: u16, args: &Args) {
+    let config = Config::load("aoc.toml".as_ref())
+        .unwrap_or_else(|err| exit_with_error(&format!("could not read aoc.toml: {}", err)));
+
+    let now = Utc::now();
+    let days = if args.days.is_empty() {
+        match latest_unlocked_day(year, now) {
+            Some(latest) => vec![latest],
+            None => exit_with_error(&format!(
+                "the {} event hasn't started yet, day 1 unlocks at {}",
+                year,
+                unlock_time(year, 1)
+            )),
+        }
+    } else {
+        args.days.clone()
+    };
+
+    let mut failed = false;
+    for day in days {
+        let path = input_path(year, day);
+        match fetch_input(&config, year, day, &path, args.force, now) {
+            Ok(Fetched::Cached) => println!(
+                "Day {:02}: already saved at {}, use --force to download it again",
+                day,
+                path.display()
+            ),
+            Ok(Fetched::Downloaded(bytes)) => {
+                println!(
+                    "Day {:02}: saved {} bytes to {}",
+                    day,
+                    bytes,
+                    path.display()
+                )
+            }
+            Err(err) => {
+                println!("Day {:02}: {}", day, err);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+// Send the answer for one part of a day, from --answer or by running the solver on its input,
+// and record what the site says in the year's submission history.
+// Answers the history already knows are wrong aren't sent.
+pub fn submit(year: u16, args: &Args) {
+    let day = args.days[0];
+    let part = args.part.expect("submit should always be given a part");
+    if !is_unlocked(year, day, Utc::now()) {
+        exit_with_error(&format!(
+            "day {} of {} hasn't unlocked yet, it unlocks at {}",
+            day,
+            year,
+            unlock_time(year, day)
+        ));
+    }
+
+    let answer = match &args.answer {
+        Some(answer) => answer.clone(),
+        None => {
+            let Some(solver) = find_solver(year, day) else {
+                exit_with_error(&format!("no solver registered for day {}", day));
+            };
+            let source = args
+                .inputs
+                .first()
+                .map_or(InputSource::Default, |arg| InputSource::from_arg(arg));
+            let mut stdin = String::new();
+            if matches!(source, InputSource::Stdin) {
+                let _ = io::stdin().read_to_string(&mut stdin);
+            }
+            let input = source.read(solver, &stdin).unwrap_or_else(|err| {
+                exit_with_error(&format!("could not read {}: {}", source.label(solver), err))
+            });
+            let run = solver
+                .run(&input, Some(part))
+                .unwrap_or_else(|err| exit_with_error(&err.to_string()));
+            let solution = if part == Part::One {
+                run.part1
+            } else {
+                run.part2
+            };
+            if !solution.is_solved() {
+                exit_with_error(&format!("day {} has no answer for that part", day));
+            }
+            solution.to_string()
+        }
+    };
+    let level = if part == Part::One { 1 } else { 2 };
+    println!("Submitting {} for day {} part {}...", answer, day, level);
+
+    let config = Config::load("aoc.toml".as_ref())
+        .unwrap_or_else(|err| exit_with_error(&format!("could not read aoc.toml: {}", err)));
+    let client = Client::new(&config).unwrap_or_else(|err| exit_with_error(&err));
+    let path = History::path(year);
+    let mut history = History::load(&path).unwrap_or_else(|err| {
+        exit_with_error(&format!(
+            "could not read history {}: {}",
+            path.display(),
+            err
+        ))
+    });
+
+    let feedback = submit_answer(&client, &mut history, year, day, part, &answer)
+        .unwrap_or_else(|err| exit_with_error(&format!("not submitted: {}", err)));
+    // The input directory may not exist if the answer was worked out from another input
+    let saved = std::fs::create_dir_all(path.parent().unwrap()).and_then(|_| history.save(&path));
+    if let Err(err) = saved {
+        eprintln!("Could not save history to {}: {}", path.display(), err);
+    }
+
+    println!("{}", feedback);
+    match history.bounds(day, part) {
+        _ if feedback == Feedback::Correct => return,
+        (Some(low), Some(high)) => println!("The answer is between {} and {}", low, high),
+        (Some(low), None) => println!("The answer is above {}", low),
+        (None, Some(high)) => println!("The answer is below {}", high),
+        (None, None) => {}
+    }
+    std::process::exit(1);
+}
+
+// Create a day's module, register it and add an empty input file, from the top of the repository
+pub fn new(year: u16, args: &Args) {
+    let day = args.days[0];
+    let title = args.title.clone().unwrap_or_else(|| format!("Day {}", day));
+    let changed = new_day("".as_ref(), year, day, &title)
+        .unwrap_or_else(|err| exit_with_error(&format!("could not create day {}: {}", day, err)));
+    for path in changed {
+        println!("Wrote {}", path.display());
+    }
+    println!(
+        "\nDay {} is registered, and will show up in --list once the runner is rebuilt.",
+        day
+    );
+}
+
+// Run one day on its input, and its example input if there is one (or on the --input files),
+// then again whenever one of them changes, showing how the answers and times changed.
+// When the day's source changes, the runner is rebuilt and started again in place of this one.
+pub fn watch(year: u16, args: &Args) {
+    let day = args.days[0];
+    let Some(solver) = find_solver(year, day) else {
+        exit_with_error(&format!("no solver registered for day {}", day));
+    };
+    let inputs: Vec<PathBuf> = if args.inputs.is_empty() {
+        vec![input_path(year, day), example_path(year, day)]
+    } else if args.inputs.iter().any(|input| input == "-") {
+        exit_with_error("watch can only watch files, not stdin");
+    } else {
+        args.inputs.iter().map(PathBuf::from).collect()
+    };
+    let source = source_path(year, day);
+
+    let mut paths = inputs.clone();
+    paths.push(source.clone());
+    let mut watcher = Watcher::new(paths, Duration::from_millis(500));
+    let mut previous: HashMap<PathBuf, Run> = HashMap::new();
+    let mut changed = inputs.clone();
+    loop {
+        for path in inputs.iter().filter(|path| changed.contains(path)) {
+            // The example input is optional, so it's only mentioned once it exists
+            let Ok(input) = read_to_string(path) else {
+                if *path != example_path(year, day) {
+                    println!("\nCould not read {}, waiting for it...", path.display());
+                }
+                previous.remove(path);
+                continue;
+            };
+            println!(
+                "\n=== Day {:02} ({}) at {} ===",
+                day,
+                path.display(),
+                chrono::Local::now().format("%H:%M:%S")
+            );
+            match solver.run(&input, args.part) {
+                Ok(run) => {
+                    for line in compare(&run, previous.get(path)) {
+                        println!("  · {}", line);
+                    }
+                    previous.insert(path.clone(), run);
+                }
+                Err(err) => println!("  · Error: {}", err),
+            }
+        }
+
+        println!("\nWatching for changes, press Ctrl-C to stop...");
+        changed = watcher.wait();
+        if changed.contains(&source) {
+            rebuild_and_restart(&source);
+            // The build failed, so carry on with this one until the source changes again
+            changed.clear();
+        }
+    }
+}
+
+// Rebuild the runner after a change to `source`, then run the new build with the same
+// arguments in place of this one, exiting when it does. A failed build is left for the caller.
+fn rebuild_and_restart(source: &std::path::Path) {
+    println!("\n{} changed, rebuilding...", source.display());
+    // Looked up first, since on Linux it names the old file once the build has replaced it
+    let exe = env::current_exe().expect("the runner should know where it is");
+    let mut build = process::Command::new("cargo");
+    build.arg("build");
+    if !cfg!(debug_assertions) {
+        build.arg("--release");
+    }
+    match build.status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            println!("The build failed, fix it and save again");
+            return;
+        }
+        Err(err) => {
+            println!("Could not run cargo: {}", err);
+            return;
+        }
+    }
+
+    match process::Command::new(exe)
+        .args(env::args().skip(1))
+        .status()
+    {
+        Ok(status) => process::exit(status.code().unwrap_or(1)),
+        Err(err) => println!("Could not restart: {}", err),
+    }
+}
+
+// Print each step of a run with its timing, and what it allocated when counting allocations.
+// Parts that weren't run have no time to show.
+fn print_run(run: &Run) {
+    let memory_of = |step: fn(&Memory) -> Option<Usage>| run.memory.as_ref().and_then(step);
+    let with_memory = |time: Duration, memory: Option<Usage>| match memory {
+        Some(memory) => format!("{:.4} ms, {}", as_ms(time), memory),
+        None => format!("{:.4} ms", as_ms(time)),
+    };
+    println!(
+        "  · Parse: {}",
+        with_memory(run.parse_time, memory_of(|memory| Some(memory.parse)))
+    );
+    let parts = [
+        (&run.part1, run.part1_time, memory_of(|memory| memory.part1)),
+        (&run.part2, run.part2_time, memory_of(|memory| memory.part2)),
+    ];
+    for (i, (solution, time, memory)) in parts.into_iter().enumerate() {
+        match time {
+            Some(time) => println!(
+                "  · Part {}: {} ({})",
+                i + 1,
+                solution,
+                with_memory(time, memory)
+            ),
+            None => println!("  · Part {}: {}", i + 1, solution),
+        }
+    }
+    println!(
+        "  · Elapsed: {}",
+        with_memory(run.total_time(), memory_of(|memory| Some(memory.total)))
+    );
+}
+
+// Print the answers, then a table of stats for each step, in milliseconds.
+// Given a baseline, each step's median is also compared against the baseline's.
+fn print_bench(bench: &Bench, year: u16, label: &str, baseline: Option<&Baseline>) {
+    println!("  · Part 1: {}", bench.run.part1);
+    println!("  · Part 2: {}", bench.run.part2);
+    println!(
+        "    {:<6}{:>10}{:>10}{:>10}{:>10}{:>10}{}",
+        "(ms)",
+        "min",
+        "median",
+        "mean",
+        "stddev",
+        "p95",
+        if baseline.is_some() {
+            "  vs baseline"
+        } else {
+            ""
+        }
+    );
+    for (step, stats) in bench.steps() {
+        let delta = baseline
+            .and_then(|baseline| baseline.get(year, label, step))
+            .map(|old| match percent_change(old, stats.median) {
+                Some(change) => format!("  {:+.1}%", change),
+                None => "  n/a".to_string(),
+            });
+        println!(
+            "    {:<6}{:>10.4}{:>10.4}{:>10.4}{:>10.4}{:>10.4}{}",
+            step,
+            as_ms(stats.min),
+            as_ms(stats.median),
+            as_ms(stats.mean),
+            as_ms(stats.stddev),
+            as_ms(stats.p95),
+            delta.unwrap_or_default()
+        );
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+// Describe the parts without an answer, eg "unsolved" or "part 2 skipped", or None if both are solved
+fn unsolved_note(p1: &Solution, p2: &Solution) -> Option<String> {
+    match (p1.is_solved(), p2.is_solved()) {
+        (true, true) => None,
+        (false, false) if p1 == p2 => Some(p1.to_string()),
+        _ => Some(
+            [p1, p2]
+                .iter()
+                .enumerate()
+                .filter(|(_, part)| !part.is_solved())
+                .map(|(i, part)| format!("part {} {}", i + 1, part))
+                .join(", "),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsolved_note() {
+        let answer = Solution::from(1u8);
+        assert_eq!(unsolved_note(&answer, &answer), None);
+        assert_eq!(
+            unsolved_note(&Solution::Unsolved, &Solution::Unsolved),
+            Some("unsolved".to_string())
+        );
+        assert_eq!(
+            unsolved_note(&answer, &Solution::Skipped),
+            Some("part 2 skipped".to_string())
+        );
+        assert_eq!(
+            unsolved_note(&Solution::Unsolved, &Solution::Skipped),
+            Some("part 1 unsolved, part 2 skipped".to_string())
+        );
+    }
+
+    #[test]
+    fn test_input_source() {
+        assert!(matches!(InputSource::from_arg("-"), InputSource::Stdin));
+        let source = InputSource::from_arg("input/other.txt");
+        let solver = find_solver(2023, 1).unwrap();
+        assert_eq!(source.label(solver), "input/other.txt");
+        assert_eq!(
+            InputSource::Default.label(solver),
+            solver.input_path().display().to_string()
+        );
+        assert_eq!(InputSource::Stdin.read(solver, "1abc2").unwrap(), "1abc2");
+    }
+}
@@ -0,0 +1,128 @@
+use std::fmt::{Display, Formatter};
+use std::num::{ParseIntError, TryFromIntError};
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// An error from solving a puzzle, almost always because the input is not what the solver expected.
+// Solvers usually only know the message and maybe the position; the day is filled in by the runner.
+// Lines and columns are 1-based, like an editor would show them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub day: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Self {
+        Error {
+            day: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    // The positions only get set once, so the innermost (most precise) position wins
+    // when an error is passed up through several layers of parsing.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let location = [
+            self.day.map(|day| format!("day {}", day)),
+            self.line.map(|line| format!("line {}", line)),
+            self.column.map(|column| format!("column {}", column)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if location.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", location.join(", "), self.message)
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Error::new(format!("invalid number: {}", err))
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(err: TryFromIntError) -> Self {
+        Error::new(format!("number out of range: {}", err))
+    }
+}
+
+// Parse a number, keeping the offending text in the error message
+pub fn parse_number<T: std::str::FromStr>(s: &str) -> Result<T> {
+    s.trim()
+        .parse()
+        .map_err(|_| Error::new(format!("invalid number {:?}", s)))
+}
+
+// Run a parser over each line, attaching the line number to any error
+pub fn parse_lines<T>(lines: &[&str], parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Error::new("bad input").to_string(), "bad input");
+        let err = Error::new("invalid tile 'X'")
+            .at_line(3)
+            .at_column(5)
+            .for_day(10);
+        assert_eq!(
+            err.to_string(),
+            "day 10, line 3, column 5: invalid tile 'X'"
+        );
+    }
+
+    #[test]
+    fn test_innermost_position_wins() {
+        let err = Error::new("oops").at_line(2).at_line(7);
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn test_parse_lines() {
+        let lines = ["1", "2", "x", "4"];
+        assert_eq!(
+            parse_lines(&lines[..2], parse_number::<u32>),
+            Ok(vec![1, 2])
+        );
+        let err = parse_lines(&lines, parse_number::<u32>).unwrap_err();
+        assert_eq!(err.line, Some(3));
+        assert_eq!(err.message, "invalid number \"x\"");
+    }
+}
@@ -0,0 +1,105 @@
+use crate::etc::calendar::{is_unlocked, unlock_time};
+use crate::etc::client::{Client, Config};
+use chrono::{DateTime, Utc};
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    // Already saved, so nothing was downloaded
+    Cached,
+    // Downloaded and saved, with its size in bytes
+    Downloaded(usize),
+}
+
+// Download the input for a day to `path`, unless it's already there (or `force` is given).
+// Inputs never change, so a saved one is never downloaded again, and a day that hasn't
+// unlocked by `now` isn't asked for at all.
+pub fn fetch_input(
+    config: &Config,
+    year: u16,
+    day: u8,
+    path: &Path,
+    force: bool,
+    now: DateTime<Utc>,
+) -> Result<Fetched, String> {
+    if path.exists() && !force {
+        return Ok(Fetched::Cached);
+    }
+    if !is_unlocked(year, day, now) {
+        return Err(format!(
+            "day {} of {} hasn't unlocked yet, it unlocks at {}",
+            day,
+            year,
+            unlock_time(year, day)
+        ));
+    }
+
+    let client = Client::new(config)?;
+    let input = client.get(&format!("/{}/day/{}/input", year, day))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|err| format!("could not create {}: {}", dir.display(), err))?;
+    }
+    fs::write(path, &input)
+        .map_err(|err| format!("could not write {}: {}", path.display(), err))?;
+    Ok(Fetched::Downloaded(input.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::client::{stub, USER_AGENT};
+    use chrono::TimeZone;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("2023/day05.txt")
+    }
+
+    fn december(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2023, 12, day, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (config, requests) = stub::server(200, "seeds: 1 2\n");
+        let path = temp_path("cache");
+
+        let fetched = fetch_input(&config, 2023, 5, &path, false, december(5));
+        assert_eq!(fetched, Ok(Fetched::Downloaded(11)));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "seeds: 1 2\n");
+        let request = requests.recv().unwrap();
+        assert_eq!(request.url, "/2023/day/5/input");
+        assert_eq!(request.cookie, "session=abc");
+        assert_eq!(request.user_agent, USER_AGENT);
+
+        // Saved inputs aren't downloaded again unless forced, and don't need a session
+        let no_session = Config::default();
+        let fetched = fetch_input(&no_session, 2023, 5, &path, false, december(5));
+        assert_eq!(fetched, Ok(Fetched::Cached));
+        let fetched = fetch_input(&config, 2023, 5, &path, true, december(5));
+        assert_eq!(fetched, Ok(Fetched::Downloaded(11)));
+        assert_eq!(requests.try_iter().count(), 1);
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_errors() {
+        let (config, requests) = stub::server(400, "Please log in");
+        let path = temp_path("errors");
+
+        let err = fetch_input(&config, 2023, 5, &path, false, december(4)).unwrap_err();
+        assert!(err.starts_with("day 5 of 2023 hasn't unlocked yet"));
+        let err = fetch_input(&config, 2023, 5, &path, false, december(5)).unwrap_err();
+        assert!(err.contains("the session token may be wrong or expired"));
+        let err = fetch_input(&Config::default(), 2023, 5, &path, false, december(5));
+        assert!(err.unwrap_err().starts_with("no session token"));
+
+        // Only the unlocked day with a session was asked for, and nothing was saved
+        assert_eq!(requests.try_iter().count(), 1);
+        assert!(!path.exists());
+    }
+}
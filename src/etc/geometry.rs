@@ -0,0 +1,420 @@
+use crate::etc::error::parse_number;
+use crate::etc::grid::Pos;
+use crate::etc::{Error, Result};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+// A point on the plane. y grows downwards like the rows of a grid, so that a grid position
+// (row, column) is the point (column, row) and Up is a step of -1 in y.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2 { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn from_pos((row, col): Pos) -> Self {
+        Point2::new(col as i64, row as i64)
+    }
+
+    // The grid position of the point, if it isn't left of or above the grid
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn step(self, direction: Direction4) -> Self {
+        self + direction.to_point()
+    }
+
+    pub fn neighbors4(self) -> [Self; 4] {
+        Direction4::ALL.map(|direction| self.step(direction))
+    }
+
+    pub fn neighbors8(self) -> [Self; 8] {
+        Direction8::ALL.map(|direction| self + direction.to_point())
+    }
+}
+
+// A point in space, eg a corner of a brick or a hailstone's position
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i64 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+}
+
+// The arithmetic is the same for both, coordinate by coordinate
+macro_rules! impl_point_ops {
+    ($point:ident { $($coord:ident),+ }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($coord: self.$coord + other.$coord),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($coord: self.$coord - other.$coord),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($coord: -self.$coord),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, k: i64) -> Self {
+                $point { $($coord: self.$coord * k),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl Display for $point {
+            fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+                let coords = [$(self.$coord.to_string()),+];
+                write!(f, "({})", coords.join(", "))
+            }
+        }
+
+        // Comma separated coordinates, eg 1,0,1 or 19, 13, 30
+        impl FromStr for $point {
+            type Err = Error;
+
+            fn from_str(s: &str) -> Result<Self> {
+                let coords = s
+                    .split(',')
+                    .map(parse_number::<i64>)
+                    .collect::<Result<Vec<_>>>()?;
+                match coords[..] {
+                    [$($coord),+] => Ok($point { $($coord),+ }),
+                    _ => Err(Error::new(format!(
+                        "expected {} coordinates, found {}",
+                        [$(stringify!($coord)),+].len(),
+                        coords.len()
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+// The four orthogonal directions, in clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction4 {
+    pub const ALL: [Direction4; 4] = [
+        Direction4::Up,
+        Direction4::Right,
+        Direction4::Down,
+        Direction4::Left,
+    ];
+
+    // Accepts U/D/L/R, N/S/E/W and arrows (^ v < > and ↑ ↓ ← →)
+    pub fn from_char(c: char) -> Result<Self> {
+        match c {
+            'U' | 'N' | '^' | '↑' => Ok(Direction4::Up),
+            'R' | 'E' | '>' | '→' => Ok(Direction4::Right),
+            'D' | 'S' | 'v' | '↓' => Ok(Direction4::Down),
+            'L' | 'W' | '<' | '←' => Ok(Direction4::Left),
+            _ => Err(Error::new(format!("invalid direction {:?}", c))),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction4::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction4::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction4::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction4::Up | Direction4::Down)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        !self.is_vertical()
+    }
+
+    // Bouncing off a / mirror: right turns up, up turns right, and so on
+    pub fn reflect_slash(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Right,
+            Direction4::Right => Direction4::Up,
+            Direction4::Down => Direction4::Left,
+            Direction4::Left => Direction4::Down,
+        }
+    }
+
+    // Bouncing off a \ mirror: right turns down, up turns left, and so on
+    pub fn reflect_backslash(self) -> Self {
+        match self {
+            Direction4::Up => Direction4::Left,
+            Direction4::Right => Direction4::Down,
+            Direction4::Down => Direction4::Right,
+            Direction4::Left => Direction4::Up,
+        }
+    }
+
+    // A step in (row, column) terms, for Grid::step
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction4::Up => (-1, 0),
+            Direction4::Right => (0, 1),
+            Direction4::Down => (1, 0),
+            Direction4::Left => (0, -1),
+        }
+    }
+
+    pub fn to_point(self) -> Point2 {
+        let (dy, dx) = self.delta();
+        Point2::new(dx as i64, dy as i64)
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction4::Up => '↑',
+            Direction4::Right => '→',
+            Direction4::Down => '↓',
+            Direction4::Left => '←',
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction4::from_char(c),
+            _ => Err(Error::new(format!("invalid direction {:?}", s))),
+        }
+    }
+}
+
+// The eight directions including the diagonals, in clockwise order
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    // An eighth of a turn
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    pub fn delta(self) -> (isize, isize) {
+        match self {
+            Direction8::Up => (-1, 0),
+            Direction8::UpRight => (-1, 1),
+            Direction8::Right => (0, 1),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (1, 0),
+            Direction8::DownLeft => (1, -1),
+            Direction8::Left => (0, -1),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+
+    pub fn to_point(self) -> Point2 {
+        let (dy, dx) = self.delta();
+        Point2::new(dx as i64, dy as i64)
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(direction: Direction4) -> Self {
+        Direction8::ALL[direction as usize * 2]
+    }
+}
+
+// Accepts the Direction4 forms, compass points like NE and diagonal arrows like ↗
+impl FromStr for Direction8 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "NE" | "UR" | "↗" => Ok(Direction8::UpRight),
+            "SE" | "DR" | "↘" => Ok(Direction8::DownRight),
+            "SW" | "DL" | "↙" => Ok(Direction8::DownLeft),
+            "NW" | "UL" | "↖" => Ok(Direction8::UpLeft),
+            _ => s.parse::<Direction4>().map(Direction8::from),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_points() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(-3, 5);
+        assert_eq!(a + b, Point2::new(-2, 7));
+        assert_eq!(a - b, Point2::new(4, -3));
+        assert_eq!(-a * 3, Point2::new(-3, -6));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.step(Direction4::Up), Point2::new(1, 1));
+        assert_eq!(Point2::from_pos((2, 1)), a);
+        assert_eq!(a.to_pos(), Some((2, 1)));
+        assert_eq!(b.to_pos(), None);
+        assert_eq!(Point2::ORIGIN.neighbors8().len(), 8);
+
+        let mut p = Point3::new(1, 0, 1);
+        p += Point3::new(0, 2, 0);
+        assert_eq!(p, Point3::new(1, 2, 1));
+        assert_eq!(p.manhattan(Point3::ORIGIN), 4);
+        assert_eq!(p.chebyshev(Point3::ORIGIN), 2);
+        assert_eq!(p.to_string(), "(1, 2, 1)");
+    }
+
+    #[test]
+    fn test_parse_points() {
+        assert_eq!("19, 13, 30".parse(), Ok(Point3::new(19, 13, 30)));
+        assert_eq!("-1,4".parse(), Ok(Point2::new(-1, 4)));
+        let err = "1,2".parse::<Point3>().unwrap_err();
+        assert_eq!(err.message, "expected 3 coordinates, found 2");
+        assert!("1,x".parse::<Point2>().is_err());
+    }
+
+    #[test]
+    fn test_turns() {
+        use Direction4::*;
+        assert_eq!(Up.turn_right(), Right);
+        assert_eq!(Up.turn_left(), Left);
+        assert_eq!(Left.reverse(), Right);
+        for direction in Direction4::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reflect_slash().reflect_slash(), direction);
+            assert_eq!(direction.to_point() * -1, direction.reverse().to_point());
+        }
+        assert_eq!(Right.reflect_slash(), Up);
+        assert_eq!(Right.reflect_backslash(), Down);
+
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+        assert!(Direction8::DownLeft.is_diagonal());
+        assert_eq!(Direction8::from(Left), Direction8::Left);
+    }
+
+    #[test]
+    fn test_parse_directions() {
+        for (s, direction) in [
+            ("U", Direction4::Up),
+            ("E", Direction4::Right),
+            ("v", Direction4::Down),
+            ("←", Direction4::Left),
+        ] {
+            assert_eq!(s.parse(), Ok(direction));
+        }
+        assert_eq!(Direction4::Down.arrow(), '↓');
+        assert!("X".parse::<Direction4>().is_err());
+        assert!("UU".parse::<Direction4>().is_err());
+        assert_eq!("SW".parse(), Ok(Direction8::DownLeft));
+        assert_eq!("↗".parse(), Ok(Direction8::UpRight));
+        assert_eq!("N".parse(), Ok(Direction8::Up));
+    }
+}
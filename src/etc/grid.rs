@@ -0,0 +1,407 @@
+use crate::etc::{Error, Result};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+// A position in a grid, as (row, column) from the top left
+pub type Pos = (usize, usize);
+
+// Steps to the orthogonal neighbors as (row, column) deltas: up, down, left, right
+pub const DELTAS4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+// Steps to all eight neighbors, going clockwise from the top left
+pub const DELTAS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+// A rectangular 2d grid of cells, stored row by row.
+// Positions outside the grid are never an error: `get` and friends return None for them,
+// so walking off the edge is just the end of a path.
+// There's always at least one row and one column, so the row and column views have a width
+// to step by, and stay that way through transposing and rotating.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    // Panics if either size is 0, like building a grid from no rows is an error
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        assert_not_empty(rows, cols);
+        Grid {
+            rows,
+            cols,
+            cells: vec![fill; rows * cols],
+        }
+    }
+
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(Pos) -> T) -> Self {
+        assert_not_empty(rows, cols);
+        let cells = (0..rows)
+            .flat_map(|i| (0..cols).map(move |j| (i, j)))
+            .map(&mut f)
+            .collect();
+        Grid { rows, cols, cells }
+    }
+
+    // The rows have to all be the same width, and there has to be at least one cell
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if cols == 0 {
+            return Err(Error::new("expected a grid with at least one cell"));
+        }
+        if let Some(i) = rows.iter().position(|row| row.len() != cols) {
+            return Err(Error::new(format!(
+                "expected a row of width {}, found {}",
+                cols,
+                rows[i].len()
+            ))
+            .at_line(i + 1));
+        }
+        Ok(Grid {
+            rows: rows.len(),
+            cols,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    // Parse a grid from lines of text, turning each character into a cell with `f`.
+    // Errors from `f` get the line and column of the character added to them.
+    pub fn from_lines<'a>(
+        lines: impl IntoIterator<Item = &'a str>,
+        mut f: impl FnMut(char) -> Result<T>,
+    ) -> Result<Self> {
+        let rows = lines
+            .into_iter()
+            .enumerate()
+            .map(|(i, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(j, c)| f(c).map_err(|err| err.at_line(i + 1).at_column(j + 1)))
+                    .collect::<Result<Vec<T>>>()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_rows(rows)
+    }
+
+    pub fn parse(input: &str, f: impl FnMut(char) -> Result<T>) -> Result<Self> {
+        Self::from_lines(input.lines(), f)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, (i, j): Pos) -> bool {
+        i < self.rows && j < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.0 * self.cols + pos.1])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.cols + pos.1])
+        } else {
+            None
+        }
+    }
+
+    // For positions that come from arithmetic and may have gone negative
+    pub fn get_signed(&self, (i, j): (isize, isize)) -> Option<&T> {
+        self.get((usize::try_from(i).ok()?, usize::try_from(j).ok()?))
+    }
+
+    // Indexing as if the grid repeated forever in every direction
+    pub fn get_wrapping(&self, (i, j): (isize, isize)) -> &T {
+        let i = i.rem_euclid(self.rows as isize) as usize;
+        let j = j.rem_euclid(self.cols as isize) as usize;
+        &self[(i, j)]
+    }
+
+    // The position `delta` away from `pos`, if it's still in the grid
+    pub fn step(&self, (i, j): Pos, (di, dj): (isize, isize)) -> Option<Pos> {
+        let pos = (i.checked_add_signed(di)?, j.checked_add_signed(dj)?);
+        self.contains(pos).then_some(pos)
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DELTAS4
+            .iter()
+            .filter_map(move |&delta| self.step(pos, delta))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DELTAS8
+            .iter()
+            .filter_map(move |&delta| self.step(pos, delta))
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.cells[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        &mut self.cells[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn column(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(j < self.cols, "column {} out of range", j);
+        self.cells.iter().skip(j).step_by(self.cols)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols)
+    }
+
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.cols).map(move |j| self.column(j))
+    }
+
+    // Every cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    // Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows).flat_map(move |i| (0..cols).map(move |j| (i, j)))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // The first position, row by row, whose cell matches
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Pos> {
+        let index = self.cells.iter().position(&mut predicate)?;
+        Some((index / self.cols, index % self.cols))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Rows become columns, so the cell at (i, j) moves to (j, i)
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols, self.rows, |(i, j)| self[(j, i)].clone())
+    }
+
+    // A quarter turn clockwise, so the left column becomes the top row
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols, self.rows, |(i, j)| {
+            self[(self.rows - 1 - j, i)].clone()
+        })
+    }
+
+    // A quarter turn counter-clockwise, so the top row becomes the left column
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols, self.rows, |(i, j)| {
+            self[(j, self.cols - 1 - i)].clone()
+        })
+    }
+
+    // Draw the grid a character per cell, a line per row, eg to print a path over it
+    pub fn render(&self, mut f: impl FnMut(Pos, &T) -> char) -> String {
+        let mut out = String::with_capacity(self.rows * (self.cols + 1));
+        for (pos, cell) in self.cells() {
+            if pos.0 > 0 && pos.1 == 0 {
+                out.push('\n');
+            }
+            out.push(f(pos, cell));
+        }
+        out
+    }
+}
+
+impl Grid<char> {
+    // A grid of the characters themselves, for when any character is fine
+    pub fn parse_chars(input: &str) -> Result<Self> {
+        Self::parse(input, Ok)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): Pos) -> &T {
+        assert!(
+            self.contains((i, j)),
+            "position ({}, {}) out of range for a {}x{} grid",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        &self.cells[i * self.cols + j]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (i, j): Pos) -> &mut T {
+        assert!(
+            self.contains((i, j)),
+            "position ({}, {}) out of range for a {}x{} grid",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        &mut self.cells[i * self.cols + j]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn assert_not_empty(rows: usize, cols: usize) {
+    assert!(
+        rows > 0 && cols > 0,
+        "a grid needs at least one cell, not {}x{}",
+        rows,
+        cols
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_grid() -> Grid<char> {
+        Grid::parse_chars("abc\ndef").unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = test_grid();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 2)], 'f');
+        assert_eq!(grid.to_string(), "abc\ndef");
+
+        let digits = Grid::parse("12\n34", |c| {
+            c.to_digit(10)
+                .ok_or_else(|| Error::new(format!("expected a digit, found {:?}", c)))
+        });
+        assert_eq!(digits.unwrap().row(1), [3, 4]);
+
+        let err = Grid::parse("12\n3x", |c| c.to_digit(10).ok_or(Error::new("bad"))).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+        let err = Grid::parse_chars("abc\nde").unwrap_err();
+        assert_eq!(err.message, "expected a row of width 3, found 2");
+        assert_eq!(err.line, Some(2));
+        assert!(Grid::parse_chars("").is_err());
+    }
+
+    #[test]
+    fn test_access() {
+        let mut grid = test_grid();
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get_signed((-1, 0)), None);
+        assert_eq!(grid.get_signed((1, 1)), Some(&'e'));
+        assert_eq!(grid.get_wrapping((-1, -1)), &'f');
+        assert_eq!(grid.get_wrapping((5, 7)), &'e');
+        assert_eq!(grid.step((0, 0), (0, -1)), None);
+        assert_eq!(grid.step((0, 0), (1, 2)), Some((1, 2)));
+        assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+
+        *grid.get_mut((0, 0)).unwrap() = 'z';
+        grid[(1, 0)] = 'y';
+        grid.row_mut(1)[1] = 'x';
+        assert_eq!(grid.to_string(), "zbc\nyxf");
+    }
+
+    #[test]
+    #[should_panic(expected = "a grid needs at least one cell, not 2x0")]
+    fn test_no_columns() {
+        Grid::from_fn(2, 0, |_| 'a');
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbors8((0, 2)).collect::<Vec<_>>(),
+            [(1, 2), (1, 1), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_views() {
+        let grid = test_grid();
+        assert_eq!(grid.row(0), ['a', 'b', 'c']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.column(2).rev().collect::<String>(), "fc");
+        let columns: Vec<String> = grid.iter_columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+        assert_eq!(grid.iter_rows().count(), 2);
+        assert_eq!(grid.positions().nth(4), Some((1, 1)));
+        assert_eq!(grid.cells().last(), Some(((1, 2), &'f')));
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = test_grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+        assert_eq!(grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), grid);
+
+        let upper = grid.map(|c| c.to_ascii_uppercase());
+        assert_eq!(upper.to_string(), "ABC\nDEF");
+        let rendered = grid.render(|(i, j), &c| if (i + j) % 2 == 0 { '#' } else { c });
+        assert_eq!(rendered, "#b#\nd#f");
+        assert_eq!(
+            Grid::from_fn(2, 2, |(i, j)| i * 2 + j).to_string(),
+            "01\n23"
+        );
+    }
+}
@@ -0,0 +1,426 @@
+use crate::etc::math::gcd_i128;
+use crate::etc::{Error, Result};
+use std::fmt::{Display, Formatter};
+
+// Exact polynomial interpolation, for sequences that turn out to be polynomials, eg a history
+// of readings or the number of tiles reached after every grid's width of steps.
+// Everything is done in integers or fractions, so nothing is lost to floating point even for
+// huge arguments, and anything too big for an i128 is an error rather than a wrong answer.
+
+// A fraction, always kept in lowest terms with a positive denominator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i128,
+    denom: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { numer: 0, denom: 1 };
+    pub const ONE: Rational = Rational { numer: 1, denom: 1 };
+
+    pub fn new(numer: i128, denom: i128) -> Self {
+        Rational::checked_new(numer, denom).expect("a fraction of i128::MIN can't be reduced")
+    }
+
+    pub fn numer(self) -> i128 {
+        self.numer
+    }
+
+    pub fn denom(self) -> i128 {
+        self.denom
+    }
+
+    pub fn is_integer(self) -> bool {
+        self.denom == 1
+    }
+
+    // The value as an i64, if it's a whole number that fits
+    pub fn to_i64(self) -> Option<i64> {
+        self.is_integer()
+            .then(|| i64::try_from(self.numer).ok())
+            .flatten()
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational::new(n as i128, 1)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Self {
+        Rational::new(n, 1)
+    }
+}
+
+// The arithmetic is checked, giving None where an i128 would overflow, since release builds
+// would otherwise wrap around to a wrong answer
+impl Rational {
+    fn checked_new(numer: i128, denom: i128) -> Option<Self> {
+        assert!(denom != 0, "a fraction can't have a zero denominator");
+        let g = i128::try_from(gcd_i128(numer, denom))
+            .ok()?
+            .checked_mul(denom.signum())?;
+        Some(Rational {
+            numer: numer.checked_div(g)?,
+            denom: denom.checked_div(g)?,
+        })
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Rational {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom,
+        })
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let numer = self
+            .numer
+            .checked_mul(other.denom)?
+            .checked_add(other.numer.checked_mul(self.denom)?)?;
+        Rational::checked_new(numer, self.denom.checked_mul(other.denom)?)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Rational::checked_new(
+            self.numer.checked_mul(other.numer)?,
+            self.denom.checked_mul(other.denom)?,
+        )
+    }
+
+    // Also None when dividing by zero
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.numer == 0 {
+            return None;
+        }
+        Rational::checked_new(
+            self.numer.checked_mul(other.denom)?,
+            self.denom.checked_mul(other.numer)?,
+        )
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+// A polynomial with exact coefficients, lowest power first, eg [1, 0, 3] is 3x^2 + 1.
+// Trailing zero coefficients are dropped, so the zero polynomial has none.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polynomial {
+    coeffs: Vec<Rational>,
+}
+
+impl Polynomial {
+    pub fn new(mut coeffs: Vec<Rational>) -> Self {
+        while coeffs.last() == Some(&Rational::ZERO) {
+            coeffs.pop();
+        }
+        Polynomial { coeffs }
+    }
+
+    // The polynomial through (0, values[0]), (1, values[1]) and so on, built from the
+    // forward differences: p(x) = sum of Δ^k f(0) * C(x, k)
+    pub fn newton(values: &[i64]) -> Result<Self> {
+        let mut result = Polynomial::new(vec![]);
+        // C(x, k) = x (x - 1) ... (x - k + 1) / k!, built up one factor at a time
+        let mut binomial = Polynomial::new(vec![Rational::ONE]);
+        for (k, diff) in forward_differences(values)?.into_iter().enumerate() {
+            result = result.add(&binomial.scale(Rational::from(diff))?)?;
+            let factor = Polynomial::new(vec![Rational::from(-(k as i128)), Rational::ONE]);
+            binomial = binomial
+                .mul(&factor)?
+                .scale(Rational::new(1, k as i128 + 1))?;
+        }
+        Ok(result)
+    }
+
+    // The polynomial through the points, whose xs have to be distinct
+    pub fn lagrange(points: &[(i64, i64)]) -> Result<Self> {
+        let mut result = Polynomial::new(vec![]);
+        for (i, &(xi, yi)) in points.iter().enumerate() {
+            let mut basis = Polynomial::new(vec![Rational::from(yi)]);
+            for (j, &(xj, _)) in points.iter().enumerate() {
+                if i != j {
+                    assert!(xi != xj, "can't interpolate two points at x = {}", xi);
+                    let factor = Polynomial::new(vec![Rational::from(-xj), Rational::ONE]);
+                    basis = basis
+                        .mul(&factor)?
+                        .scale(Rational::new(1, xi as i128 - xj as i128))?;
+                }
+            }
+            result = result.add(&basis)?;
+        }
+        Ok(result)
+    }
+
+    pub fn coeffs(&self) -> &[Rational] {
+        &self.coeffs
+    }
+
+    // None for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coeffs.len().checked_sub(1)
+    }
+
+    pub fn eval(&self, x: impl Into<Rational>) -> Result<Rational> {
+        let x = x.into();
+        self.coeffs
+            .iter()
+            .rev()
+            .try_fold(Rational::ZERO, |acc, &coeff| {
+                acc.checked_mul(x)?.checked_add(coeff)
+            })
+            .ok_or_else(overflow)
+    }
+
+    fn scale(&self, k: Rational) -> Result<Self> {
+        let coeffs = self.coeffs.iter().map(|&coeff| coeff.checked_mul(k));
+        Ok(Polynomial::new(
+            coeffs.collect::<Option<_>>().ok_or_else(overflow)?,
+        ))
+    }
+
+    fn add(&self, other: &Self) -> Result<Self> {
+        let len = self.coeffs.len().max(other.coeffs.len());
+        let coeff =
+            |coeffs: &[Rational], i: usize| coeffs.get(i).copied().unwrap_or(Rational::ZERO);
+        let coeffs = (0..len).map(|i| coeff(&self.coeffs, i).checked_add(coeff(&other.coeffs, i)));
+        Ok(Polynomial::new(
+            coeffs.collect::<Option<_>>().ok_or_else(overflow)?,
+        ))
+    }
+
+    fn mul(&self, other: &Self) -> Result<Self> {
+        if self.coeffs.is_empty() || other.coeffs.is_empty() {
+            return Ok(Polynomial::new(vec![]));
+        }
+        let mut coeffs = vec![Rational::ZERO; self.coeffs.len() + other.coeffs.len() - 1];
+        for (i, &a) in self.coeffs.iter().enumerate() {
+            for (j, &b) in other.coeffs.iter().enumerate() {
+                coeffs[i + j] = a
+                    .checked_mul(b)
+                    .and_then(|product| coeffs[i + j].checked_add(product))
+                    .ok_or_else(overflow)?;
+            }
+        }
+        Ok(Polynomial::new(coeffs))
+    }
+}
+
+fn overflow() -> Error {
+    Error::new("interpolating needs numbers too big for an i128")
+}
+
+// Highest power first, eg 1/2x^2 - 3x + 1
+impl Display for Polynomial {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let terms: Vec<(usize, Rational)> = self
+            .coeffs
+            .iter()
+            .copied()
+            .enumerate()
+            .rev()
+            .filter(|&(_, coeff)| coeff != Rational::ZERO)
+            .collect();
+        if terms.is_empty() {
+            return write!(f, "0");
+        }
+        for (i, &(power, coeff)) in terms.iter().enumerate() {
+            let magnitude = (coeff.numer.unsigned_abs(), coeff.denom);
+            match (i, coeff.numer < 0) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => {}
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+            match magnitude {
+                (1, 1) if power > 0 => {}
+                (numer, 1) => write!(f, "{}", numer)?,
+                (numer, denom) => write!(f, "{}/{}", numer, denom)?,
+            }
+            match power {
+                0 => {}
+                1 => write!(f, "x")?,
+                _ => write!(f, "x^{}", power)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+// The first value and its first, second, ... differences, the left edge of the difference table:
+// 1 3 6 10 has differences 2 3 4, then 1 1, then 0, so this gives [1, 2, 1, 0]
+pub fn forward_differences(values: &[i64]) -> Result<Vec<i128>> {
+    let mut row: Vec<i128> = values.iter().map(|&value| value as i128).collect();
+    let mut leading = Vec::with_capacity(row.len());
+    while let Some(&first) = row.first() {
+        leading.push(first);
+        row = row
+            .windows(2)
+            .map(|pair| pair[1].checked_sub(pair[0]))
+            .collect::<Option<_>>()
+            .ok_or_else(overflow)?;
+    }
+    Ok(leading)
+}
+
+// The degree of the lowest degree polynomial through the values, or None if they're all zero.
+// Only values with a degree below values.len() - 1 actually show a pattern, since any n values
+// fit some polynomial of degree n - 1.
+pub fn degree(values: &[i64]) -> Result<Option<usize>> {
+    Ok(forward_differences(values)?
+        .iter()
+        .rposition(|&diff| diff != 0))
+}
+
+// The value at x of the polynomial through the points, without building it
+pub fn lagrange(points: &[(i64, i64)], x: impl Into<Rational>) -> Result<Rational> {
+    let x = x.into();
+    points
+        .iter()
+        .enumerate()
+        .try_fold(Rational::ZERO, |sum, (i, &(xi, yi))| {
+            let term = points
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| i != j)
+                .try_fold(Rational::from(yi), |term, (_, &(xj, _))| {
+                    assert!(xi != xj, "can't interpolate two points at x = {}", xi);
+                    term.checked_mul(x.checked_sub(Rational::from(xj))?)?
+                        .checked_div(Rational::from(xi as i128 - xj as i128))
+                })?;
+            sum.checked_add(term)
+        })
+        .ok_or_else(overflow)
+}
+
+// The value k terms after the last one, continuing the values' polynomial.
+// In whole numbers throughout, as C(x, k) is a whole number for every whole x.
+pub fn extrapolate_forward(values: &[i64], k: usize) -> Option<i64> {
+    let x = values.len() as i128 - 1 + k as i128;
+    newton_at(&forward_differences(values).ok()?, x)
+}
+
+// The value k terms before the first one
+pub fn extrapolate_backward(values: &[i64], k: usize) -> Option<i64> {
+    newton_at(&forward_differences(values).ok()?, -(k as i128))
+}
+
+fn newton_at(diffs: &[i128], x: i128) -> Option<i64> {
+    let mut sum: i128 = 0;
+    let mut binomial: i128 = 1;
+    for (k, &diff) in diffs.iter().enumerate() {
+        sum = sum.checked_add(diff.checked_mul(binomial)?)?;
+        // C(x, k + 1) = C(x, k) * (x - k) / (k + 1), which always divides exactly
+        binomial = binomial.checked_mul(x - k as i128)? / (k as i128 + 1);
+    }
+    i64::try_from(sum).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rational() {
+        let half = Rational::new(2, 4);
+        assert_eq!((half.numer(), half.denom()), (1, 2));
+        assert_eq!(half.checked_neg(), Some(Rational::new(3, -6)));
+        assert_eq!(
+            half.checked_add(Rational::new(1, 3)),
+            Some(Rational::new(5, 6))
+        );
+        assert_eq!(half.checked_sub(Rational::ONE), Some(Rational::new(-1, 2)));
+        assert_eq!(
+            half.checked_mul(Rational::from(4i64)),
+            Some(Rational::from(2i64))
+        );
+        assert_eq!(Rational::ONE.checked_div(half).unwrap().to_i64(), Some(2));
+        assert_eq!(half.checked_div(Rational::ZERO), None);
+        assert_eq!(half.to_i64(), None);
+        assert_eq!(Rational::new(-7, 3).to_string(), "-7/3");
+
+        // Overflow is caught rather than wrapping round in a release build
+        let big = Rational::from(i128::MAX);
+        assert_eq!(big.checked_add(Rational::ONE), None);
+        assert_eq!(big.checked_mul(Rational::from(2i64)), None);
+        assert_eq!(Rational::new(1, 3).checked_add(big), None);
+        assert_eq!(Rational::from(i128::MIN).checked_neg(), None);
+    }
+
+    #[test]
+    fn test_differences() {
+        assert_eq!(forward_differences(&[1, 3, 6, 10]).unwrap(), [1, 2, 1, 0]);
+        assert_eq!(degree(&[0, 3, 6, 9, 12, 15]), Ok(Some(1)));
+        assert_eq!(degree(&[10, 13, 16, 21, 30, 45]), Ok(Some(3)));
+        assert_eq!(degree(&[4, 4, 4]), Ok(Some(0)));
+        assert_eq!(degree(&[0, 0]), Ok(None));
+        assert_eq!(degree(&[]), Ok(None));
+        // Alternating extremes double the differences each time, until an i128 can't hold them
+        let extremes: Vec<i64> = (0..80)
+            .map(|i| if i % 2 == 0 { i64::MAX } else { i64::MIN })
+            .collect();
+        assert!(forward_differences(&extremes).is_err());
+        assert_eq!(extrapolate_forward(&extremes, 1), None);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate_forward(&[0, 3, 6, 9, 12, 15], 1), Some(18));
+        assert_eq!(extrapolate_forward(&[1, 3, 6, 10, 15, 21], 1), Some(28));
+        assert_eq!(extrapolate_forward(&[1, 3, 6, 10, 15, 21], 3), Some(45));
+        assert_eq!(extrapolate_backward(&[10, 13, 16, 21, 30, 45], 1), Some(5));
+        assert_eq!(extrapolate_backward(&[1, 3, 6, 10], 2), Some(0));
+        assert_eq!(extrapolate_forward(&[7], 100), Some(7));
+        assert_eq!(extrapolate_forward(&[], 1), Some(0));
+        assert_eq!(extrapolate_forward(&[0, i64::MAX], 1), None);
+    }
+
+    #[test]
+    fn test_polynomial() {
+        // The triangular numbers, x (x + 1) / 2 shifted to start at 1
+        let p = Polynomial::newton(&[1, 3, 6, 10]).unwrap();
+        assert_eq!(p.degree(), Some(2));
+        assert_eq!(p.to_string(), "1/2x^2 + 3/2x + 1");
+        assert_eq!(p.eval(5i64), Ok(Rational::from(21i64)));
+        assert_eq!(p.eval(Rational::new(1, 2)), Ok(Rational::new(15, 8)));
+
+        let q = Polynomial::lagrange(&[(1, 3), (2, 6), (3, 10), (0, 1)]).unwrap();
+        assert_eq!(q, p);
+        assert_eq!(
+            lagrange(&[(1, 3), (2, 6), (0, 1)], 5i64),
+            Ok(Rational::from(21i64))
+        );
+        let constant = Polynomial::lagrange(&[(-1, 2), (1, 2)]).unwrap();
+        assert_eq!(constant.to_string(), "2");
+        let negative = Polynomial::newton(&[0, -1, -4]).unwrap();
+        assert_eq!(negative.to_string(), "-x^2");
+        assert_eq!(Polynomial::newton(&[0, 0]).unwrap().degree(), None);
+
+        // Big enough to lose the last digits as an f64
+        let x: i64 = 26_501_365;
+        let big = Polynomial::newton(&[3, 5, 13]).unwrap();
+        assert_eq!(big.eval(x).unwrap().to_i64(), Some(3 * x * x - x + 3));
+
+        // Too big for an i128
+        let err = big.eval(i64::MAX).unwrap_err();
+        assert_eq!(
+            err.message,
+            "interpolating needs numbers too big for an i128"
+        );
+        assert!(lagrange(&[(0, 0), (1, i64::MAX)], i128::MAX).is_err());
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+pub type Task<T> = Box<dyn FnOnce() -> T + Send>;
+
+// Run the tasks on up to `jobs` threads at once, passing each result to `report` on the
+// calling thread in the order the tasks were given, as soon as it and every earlier one is done.
+// A task still running after `timeout` is reported as None. Threads can't be killed, so it's
+// left to finish in the background while the rest of the tasks carry on, still using memory
+// and a core until it finishes or the process exits. Callers should exit once they're done
+// rather than wait for it.
+pub fn run_in_order<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    jobs: usize,
+    timeout: Option<Duration>,
+    mut report: impl FnMut(Option<T>),
+) {
+    let workers = jobs.clamp(1, tasks.len().max(1));
+    let queue = Mutex::new(tasks.into_iter().enumerate().collect::<VecDeque<_>>());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || loop {
+                let Some((i, task)) = queue.lock().unwrap().pop_front() else {
+                    return;
+                };
+                let result = match timeout {
+                    Some(timeout) => run_with_timeout(task, timeout),
+                    None => Some(task()),
+                };
+                if sender.send((i, result)).is_err() {
+                    return;
+                }
+            });
+        }
+        drop(sender);
+
+        // Results can arrive out of order, so hold on to them until it's their turn
+        let mut pending = BTreeMap::new();
+        let mut next = 0;
+        for (i, result) in receiver {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&next) {
+                report(result);
+                next += 1;
+            }
+        }
+    });
+}
+
+fn run_with_timeout<T: Send + 'static>(task: Task<T>, timeout: Duration) -> Option<T> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || sender.send(task()));
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => None,
+        // The task panicked, which isn't a timeout, so pass it on like an untimed task would
+        Err(RecvTimeoutError::Disconnected) => panic!("task panicked"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sleep_for(ms: u64) -> Task<u64> {
+        Box::new(move || {
+            thread::sleep(Duration::from_millis(ms));
+            ms
+        })
+    }
+
+    #[test]
+    fn test_run_in_order() {
+        // The first task finishes last, but is still reported first
+        let tasks = vec![sleep_for(100), sleep_for(1), sleep_for(30), sleep_for(10)];
+        let mut results = Vec::new();
+        run_in_order(tasks, 4, None, |result| results.push(result));
+        assert_eq!(results, [Some(100), Some(1), Some(30), Some(10)]);
+
+        let mut results = Vec::new();
+        run_in_order(Vec::new(), 4, None, |result: Option<u64>| {
+            results.push(result)
+        });
+        assert!(results.is_empty());
+    }
+
+    #[test]
+    fn test_run_in_order_timeout() {
+        let tasks = vec![sleep_for(1), sleep_for(5_000), sleep_for(2)];
+        let mut results = Vec::new();
+        run_in_order(tasks, 1, Some(Duration::from_millis(200)), |result| {
+            results.push(result)
+        });
+        assert_eq!(results, [Some(1), None, Some(2)]);
+    }
+}
@@ -0,0 +1,217 @@
+// Number theory for the puzzles that come down to cycles: gcd/lcm, modular inverses and the
+// Chinese Remainder Theorem. Anything that can overflow returns an Option instead of wrapping.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+// The lcm of anything with 0 is 0
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+pub fn gcd_all(values: &[u64]) -> u64 {
+    values.iter().fold(0, |acc, &x| gcd(acc, x))
+}
+
+// The lcm of no values is 1, eg the period of no cycles at all
+pub fn lcm_all(values: &[u64]) -> Option<u64> {
+    values.iter().try_fold(1, |acc, &x| lcm(acc, x))
+}
+
+// The gcd g of a and b, along with x and y such that a * x + b * y = g.
+// g is never negative, and x and y are the smallest such pair.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_wide(a as i128, b as i128);
+    (g as i64, x as i64, y as i64)
+}
+
+// The gcd of wider, signed values, eg to reduce fractions. Unsigned, since the gcd of
+// i128::MIN and 0 is one more than an i128 can hold.
+pub fn gcd_i128(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// The x in 0..m with a * x = 1 (mod m), if a and m are coprime
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+// Solve x = r (mod m) for every (r, m) at once. The moduli don't have to be coprime, eg
+// x = 2 (mod 4) and x = 4 (mod 6) gives x = 10 (mod 12).
+// Returns the smallest non-negative x and the lcm of the moduli, which is the period of the
+// solutions, or None when the congruences contradict each other, a modulus isn't positive,
+// or the lcm doesn't fit in an i64.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut period): (i128, i128) = (0, 1);
+    for &(r, m) in congruences {
+        if m <= 0 {
+            return None;
+        }
+        let (r, m) = ((r as i128).rem_euclid(m as i128), m as i128);
+
+        // Find k so that x + period * k = r (mod m), which needs gcd(period, m) to divide r - x
+        let (g, p, _) = extended_gcd_wide(period, m);
+        if (r - x) % g != 0 {
+            return None;
+        }
+        let step = m / g;
+        let k = ((r - x) / g % step * p).rem_euclid(step);
+
+        x += period * k;
+        period *= step;
+        if period > i64::MAX as i128 {
+            return None;
+        }
+        x = x.rem_euclid(period);
+    }
+    Some((x as i64, period as i64))
+}
+
+// The largest r with r * r <= n
+pub fn isqrt(n: u64) -> u64 {
+    // The float is close, but can be off by one either way for large n
+    let mut r = (n as f64).sqrt() as u64;
+    while r.checked_mul(r).is_none_or(|square| square > n) {
+        r -= 1;
+    }
+    while (r + 1).checked_mul(r + 1).is_some_and(|square| square <= n) {
+        r += 1;
+    }
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 7), 7);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, 2), None);
+        assert_eq!(gcd_all(&[12, 18, 8]), 2);
+        assert_eq!(lcm_all(&[2, 3, 4]), Some(12));
+        assert_eq!(lcm_all(&[]), Some(1));
+        assert_eq!(gcd_i128(-12, 18), 6);
+        assert_eq!(gcd_i128(i128::MIN, 0), 1 << 127);
+        assert_eq!(lcm_all(&[1 << 40, 3 << 30, 5 << 20, 7]), Some(105 << 40));
+        assert_eq!(lcm_all(&[1 << 62, 3, 5]), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), None);
+        assert_eq!(crt(&[(0, i64::MAX), (0, 2)]), None);
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 6), None);
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+    }
+
+    #[test]
+    fn test_isqrt() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+        assert_eq!(isqrt((1 << 52) * (1 << 10) - 1), (1 << 31) - 1);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_gcd_lcm(a in 0..=u32::MAX as u64, b in 0..=u32::MAX as u64) {
+            let g = gcd(a, b);
+            if g != 0 {
+                prop_assert_eq!(a % g, 0);
+                prop_assert_eq!(b % g, 0);
+                prop_assert_eq!(gcd(a / g, b / g), 1);
+            }
+            prop_assert_eq!(g as u128 * lcm(a, b).unwrap() as u128, a as u128 * b as u128);
+        }
+
+        #[test]
+        fn prop_lcm_all(values in prop::collection::vec(1..1000u64, 0..6)) {
+            let l = lcm_all(&values).unwrap();
+            for &value in &values {
+                prop_assert_eq!(l % value, 0);
+            }
+            prop_assert_eq!(gcd_all(&values) == 0, values.is_empty());
+        }
+
+        #[test]
+        fn prop_extended_gcd(a in -1_000_000_000i64..1_000_000_000, b in -1_000_000_000i64..1_000_000_000) {
+            let (g, x, y) = extended_gcd(a, b);
+            prop_assert_eq!(g as u64, gcd(a.unsigned_abs(), b.unsigned_abs()));
+            prop_assert_eq!(a * x + b * y, g);
+        }
+
+        #[test]
+        fn prop_mod_inverse(a in -1_000_000i64..1_000_000, m in 1..1_000_000i64) {
+            match mod_inverse(a, m) {
+                Some(inverse) => {
+                    prop_assert!((0..m).contains(&inverse));
+                    prop_assert_eq!((a.rem_euclid(m) * inverse) % m, 1 % m);
+                }
+                None => prop_assert_ne!(gcd(a.unsigned_abs(), m as u64), 1),
+            }
+        }
+
+        // Congruences made from a known x always have a solution, even when the moduli
+        // share factors, and it's x itself modulo their lcm
+        #[test]
+        fn prop_crt(x in 0..1_000_000_000i64, moduli in prop::collection::vec(1..1000i64, 1..6)) {
+            let congruences: Vec<(i64, i64)> = moduli.iter().map(|&m| (x % m, m)).collect();
+            let (solution, period) = crt(&congruences).unwrap();
+            let moduli: Vec<u64> = moduli.iter().map(|&m| m as u64).collect();
+            prop_assert_eq!(period as u64, lcm_all(&moduli).unwrap());
+            prop_assert_eq!(solution, x % period);
+        }
+
+        #[test]
+        fn prop_isqrt(n: u64) {
+            let r = isqrt(n) as u128;
+            prop_assert!(r * r <= n as u128);
+            prop_assert!((r + 1) * (r + 1) > n as u128);
+        }
+    }
+}
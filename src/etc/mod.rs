@@ -1,3 +1,25 @@
+pub mod alloc;
+pub mod answers;
+pub mod args;
+pub mod bench;
+pub mod calendar;
+pub mod client;
+pub mod commands;
+pub mod error;
+pub mod fetch;
+pub mod geometry;
+pub mod grid;
+pub mod interpolation;
+pub mod jobs;
+pub mod math;
+pub mod polygon;
+pub mod report;
+pub mod scaffold;
 pub mod solution;
+pub mod solver;
+pub mod submit;
+pub mod watch;
 
-pub use solution::Solution;
\ No newline at end of file
+pub use error::{Error, Result};
+pub use solution::Solution;
+pub use solver::{Example, Memory, Part, Puzzle, Run, Solver};
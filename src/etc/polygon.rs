@@ -0,0 +1,281 @@
+use crate::etc::geometry::{Direction4, Point2};
+use crate::etc::{Error, Result};
+use itertools::Itertools;
+
+// A closed rectilinear polygon on the lattice, eg a loop of pipes or a dig plan's trenches.
+// Every edge is horizontal or vertical, and the last corner joins back up with the first.
+// Only the corners are kept, so a polygon millions of points around is still cheap.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polygon {
+    corners: Vec<Point2>,
+}
+
+// Which way round the corners go, as drawn with y growing downwards
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+}
+
+impl Polygon {
+    // The polygon through the points in order. Repeated points and points in the middle of a
+    // straight edge are dropped, and it's an error for two points in a row to be diagonal.
+    pub fn new(points: Vec<Point2>) -> Result<Self> {
+        for (&a, &b) in points.iter().circular_tuple_windows() {
+            if a.x != b.x && a.y != b.y {
+                return Err(Error::new(format!(
+                    "expected horizontal or vertical edges, found one from {} to {}",
+                    a, b
+                )));
+            }
+        }
+
+        let mut corners: Vec<Point2> = Vec::with_capacity(points.len());
+        for point in points {
+            if corners.last() == Some(&point) {
+                continue;
+            }
+            if let [.., a, b] = corners[..] {
+                if same_direction(b - a, point - b) {
+                    corners.pop();
+                }
+            }
+            corners.push(point);
+        }
+        // The same again where the end meets the start
+        while corners.len() > 2 {
+            let n = corners.len();
+            if corners[n - 1] == corners[0]
+                || same_direction(corners[n - 1] - corners[n - 2], corners[0] - corners[n - 1])
+            {
+                corners.pop();
+            } else if same_direction(corners[0] - corners[n - 1], corners[1] - corners[0]) {
+                corners.remove(0);
+            } else {
+                break;
+            }
+        }
+        Ok(Polygon { corners })
+    }
+
+    // The polygon traced by taking the steps from `start`, which have to lead back to it
+    pub fn from_steps(
+        start: Point2,
+        steps: impl IntoIterator<Item = (Direction4, i64)>,
+    ) -> Result<Self> {
+        let mut position = start;
+        let mut points = vec![start];
+        for (dir, length) in steps {
+            position += dir.to_point() * length;
+            points.push(position);
+        }
+        if position != start {
+            return Err(Error::new(format!(
+                "expected the steps to end back at {}, not {}",
+                start, position
+            )));
+        }
+        Polygon::new(points)
+    }
+
+    pub fn corners(&self) -> &[Point2] {
+        &self.corners
+    }
+
+    // Each edge as its start and end corner, the last one going back to the first corner
+    pub fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        self.corners.iter().copied().circular_tuple_windows()
+    }
+
+    // The shoelace formula: positive for clockwise polygons, negative for counterclockwise ones.
+    // With only horizontal and vertical edges on the lattice, it's always a whole number.
+    pub fn signed_area(&self) -> i64 {
+        self.edges()
+            .map(|(a, b)| a.x * b.y - b.x * a.y)
+            .sum::<i64>()
+            / 2
+    }
+
+    pub fn area(&self) -> u64 {
+        self.signed_area().unsigned_abs()
+    }
+
+    // None if it doesn't enclose anything, eg a line there and back again
+    pub fn orientation(&self) -> Option<Orientation> {
+        match self.signed_area() {
+            0 => None,
+            area if area > 0 => Some(Orientation::Clockwise),
+            _ => Some(Orientation::CounterClockwise),
+        }
+    }
+
+    // The number of lattice points on the edges, which is also the length all the way round
+    pub fn boundary_points(&self) -> u64 {
+        self.edges().map(|(a, b)| a.manhattan(b) as u64).sum()
+    }
+
+    // Pick's theorem, area = interior points + boundary points / 2 - 1, solved for the interior.
+    // Only holds for simple polygons, see `self_intersection`.
+    pub fn interior_points(&self) -> u64 {
+        match self.area() {
+            0 => 0,
+            area => (area + 1).saturating_sub(self.boundary_points() / 2),
+        }
+    }
+
+    // Every lattice point inside or on the edges, eg the tiles a loop covers, itself included
+    pub fn lattice_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn on_boundary(&self, point: Point2) -> bool {
+        self.edges()
+            .any(|(a, b)| overlap((a, b), (point, point)).is_some())
+    }
+
+    // Whether the point is strictly inside, ie not outside or on an edge.
+    // Casts a ray to the right and counts the vertical edges it crosses, where each edge covers
+    // its top end but not its bottom so that a ray through a corner counts once or not at all.
+    pub fn contains(&self, point: Point2) -> bool {
+        if self.on_boundary(point) {
+            return false;
+        }
+        let crossings = self
+            .edges()
+            .filter(|&(a, b)| {
+                a.x == b.x && a.x > point.x && a.y.min(b.y) <= point.y && point.y < a.y.max(b.y)
+            })
+            .count();
+        crossings % 2 == 1
+    }
+
+    // A point where the polygon touches or crosses itself, if it isn't simple.
+    // Checks every pair of edges, which is plenty for the thousand or so corners of a puzzle.
+    pub fn self_intersection(&self) -> Option<Point2> {
+        let edges: Vec<(Point2, Point2)> = self.edges().collect();
+        let n = edges.len();
+        for i in 0..n {
+            for j in i + 1..n {
+                let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+                if !adjacent {
+                    if let Some(point) = overlap(edges[i], edges[j]) {
+                        return Some(point);
+                    }
+                } else if n > 1 {
+                    // Neighbouring edges share a corner, but going straight back overlaps more
+                    let (first, second) = if j == i + 1 {
+                        (edges[i], edges[j])
+                    } else {
+                        (edges[j], edges[i])
+                    };
+                    if same_direction(first.0 - first.1, second.1 - second.0) {
+                        return Some(first.1);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+fn same_direction(a: Point2, b: Point2) -> bool {
+    a != Point2::ORIGIN && (a.x.signum(), a.y.signum()) == (b.x.signum(), b.y.signum())
+}
+
+// The top left point two horizontal or vertical segments have in common, if any.
+// Each segment is its own bounding box, so they meet wherever their boxes do.
+fn overlap((a, b): (Point2, Point2), (c, d): (Point2, Point2)) -> Option<Point2> {
+    let x = a.x.min(b.x).max(c.x.min(d.x))..=a.x.max(b.x).min(c.x.max(d.x));
+    let y = a.y.min(b.y).max(c.y.min(d.y))..=a.y.max(b.y).min(c.y.max(d.y));
+    (!x.is_empty() && !y.is_empty()).then(|| Point2::new(*x.start(), *y.start()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(size: i64) -> Polygon {
+        let steps = Direction4::ALL.map(|dir| (dir.turn_right(), size));
+        Polygon::from_steps(Point2::ORIGIN, steps).unwrap()
+    }
+
+    #[test]
+    fn test_area() {
+        let points = [(0, 0), (4, 0), (4, 4), (0, 4)].map(|(x, y)| Point2::new(x, y));
+        let polygon = Polygon::new(points.to_vec()).unwrap();
+        assert_eq!(polygon.signed_area(), 16);
+        assert_eq!(polygon.orientation(), Some(Orientation::Clockwise));
+        assert_eq!(polygon.boundary_points(), 16);
+        assert_eq!(polygon.interior_points(), 9);
+        assert_eq!(polygon.lattice_points(), 25);
+
+        let reversed = Polygon::new(points.into_iter().rev().collect()).unwrap();
+        assert_eq!(reversed.signed_area(), -16);
+        assert_eq!(reversed.orientation(), Some(Orientation::CounterClockwise));
+        assert_eq!(reversed.lattice_points(), 25);
+
+        assert_eq!(square(2).corners().len(), 4);
+        assert_eq!(square(2).lattice_points(), 9);
+    }
+
+    #[test]
+    fn test_corners() {
+        // The start is in the middle of the top edge, and the right edge is split in two
+        let points = [(2, 0), (4, 0), (4, 1), (4, 3), (0, 3), (0, 0)];
+        let polygon = Polygon::new(points.map(|(x, y)| Point2::new(x, y)).to_vec()).unwrap();
+        assert_eq!(
+            polygon.corners(),
+            [(4, 0), (4, 3), (0, 3), (0, 0)].map(|(x, y)| Point2::new(x, y))
+        );
+        let err = Polygon::new(vec![Point2::ORIGIN, Point2::new(1, 1)]).unwrap_err();
+        assert_eq!(
+            err.message,
+            "expected horizontal or vertical edges, found one from (0, 0) to (1, 1)"
+        );
+        let steps = [(Direction4::Right, 2), (Direction4::Down, 1)];
+        assert!(Polygon::from_steps(Point2::ORIGIN, steps).is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let polygon = square(4);
+        assert!(polygon.contains(Point2::new(2, 2)));
+        assert!(polygon.contains(Point2::new(1, 3)));
+        assert!(!polygon.contains(Point2::new(0, 2)));
+        assert!(polygon.on_boundary(Point2::new(0, 2)));
+        assert!(!polygon.contains(Point2::new(5, 2)));
+        assert!(!polygon.contains(Point2::new(-1, 0)));
+
+        // A U shape open at the bottom, where some rays pass through corners
+        let steps = "R6 D6 L2 U4 L2 D4 L2 U6".split(' ').map(|step| {
+            let (dir, length) = step.split_at(1);
+            (dir.parse().unwrap(), length.parse().unwrap())
+        });
+        let u = Polygon::from_steps(Point2::ORIGIN, steps).unwrap();
+        assert!(u.contains(Point2::new(1, 4)));
+        assert!(u.contains(Point2::new(5, 2)));
+        assert!(!u.contains(Point2::new(3, 4)));
+        assert!(!u.contains(Point2::new(-1, 2)));
+        assert!(!u.contains(Point2::new(-1, 6)));
+        assert_eq!(u.area(), 28);
+        assert_eq!(u.interior_points(), 13);
+        assert_eq!(u.lattice_points(), 45);
+        assert_eq!(u.self_intersection(), None);
+    }
+
+    #[test]
+    fn test_self_intersection() {
+        assert_eq!(square(3).self_intersection(), None);
+
+        // A figure of eight crossing at (1, 1)
+        let points = [(0, 0), (1, 0), (1, 2), (2, 2), (2, 1), (0, 1)];
+        let eight = Polygon::new(points.map(|(x, y)| Point2::new(x, y)).to_vec()).unwrap();
+        assert_eq!(eight.self_intersection(), Some(Point2::new(1, 1)));
+
+        // Going straight back along an edge
+        let steps = [(Direction4::Right, 2), (Direction4::Left, 2)];
+        let line = Polygon::from_steps(Point2::ORIGIN, steps).unwrap();
+        assert_eq!(line.orientation(), None);
+        assert_eq!(line.self_intersection(), Some(Point2::new(2, 0)));
+    }
+}
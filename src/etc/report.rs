@@ -0,0 +1,364 @@
+use crate::etc::alloc::Usage;
+use crate::etc::error::Error;
+use crate::etc::solution::Solution;
+use crate::etc::solver::Run;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+// How the runner prints its results. Text is the decorated output meant for reading,
+// the others print one record per run for other tools to ingest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+    Markdown,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "markdown" => Ok(Format::Markdown),
+            _ => Err(format!(
+                "unknown format {}, expected text, json, csv or markdown",
+                s
+            )),
+        }
+    }
+}
+
+// How running one day on one input ended
+pub enum Outcome {
+    Ran(Run),
+    Failed(Error),
+    // Still running after the given timeout, so abandoned
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    pub fn run(&self) -> Option<&Run> {
+        match self {
+            Outcome::Ran(run) => Some(run),
+            _ => None,
+        }
+    }
+
+    // Why there's no run, eg "timed out after 10s"
+    pub fn error(&self) -> Option<String> {
+        match self {
+            Outcome::Ran(_) => None,
+            Outcome::Failed(err) => Some(err.to_string()),
+            Outcome::TimedOut(timeout) => {
+                Some(format!("timed out after {}s", timeout.as_secs_f64()))
+            }
+        }
+    }
+}
+
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub input: String,
+    pub outcome: Outcome,
+}
+
+impl Record {
+    // "solved" if both parts have an answer, "partial" if one does, otherwise "unsolved",
+    // "error" or "timed out"
+    pub fn status(&self) -> &'static str {
+        match &self.outcome {
+            Outcome::Failed(_) => "error",
+            Outcome::TimedOut(_) => "timed out",
+            Outcome::Ran(run) => match (run.part1.is_solved(), run.part2.is_solved()) {
+                (true, true) => "solved",
+                (false, false) => "unsolved",
+                _ => "partial",
+            },
+        }
+    }
+
+    // Each part's solution and time, if the run finished
+    fn parts(&self) -> [Option<(&Solution, Option<Duration>)>; 2] {
+        match self.outcome.run() {
+            Some(run) => [
+                Some((&run.part1, run.part1_time)),
+                Some((&run.part2, run.part2_time)),
+            ],
+            None => [None, None],
+        }
+    }
+}
+
+pub fn render(format: Format, records: &[Record]) -> String {
+    match format {
+        // Text output is printed as the runs happen
+        Format::Text => String::new(),
+        Format::Json => render_json(records),
+        Format::Csv => render_csv(records),
+        Format::Markdown => render_markdown(records),
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+// The answer, if there is one. Unsolved and skipped parts only have a kind.
+fn answer(solution: &Solution) -> Option<String> {
+    solution.is_solved().then(|| solution.to_string())
+}
+
+fn usage_json(usage: Usage) -> Value {
+    json!({
+        "allocations": usage.allocations,
+        "bytes": usage.bytes,
+        "peak_bytes": usage.peak,
+    })
+}
+
+// An array of records. Answers are strings so that 128 bit answers survive intact,
+// with the Solution variant alongside, and times are in milliseconds.
+// Memory is only there when allocations were counted.
+fn render_json(records: &[Record]) -> String {
+    let records: Vec<Value> = records
+        .iter()
+        .map(|record| {
+            let part = |part: Option<(&Solution, Option<Duration>)>| {
+                part.map(|(solution, time)| {
+                    json!({
+                        "answer": answer(solution),
+                        "kind": solution.kind(),
+                        "ms": time.map(as_ms),
+                    })
+                })
+            };
+            let [part1, part2] = record.parts();
+            let run = record.outcome.run();
+            json!({
+                "year": record.year,
+                "day": record.day,
+                "title": record.title,
+                "input": record.input,
+                "status": record.status(),
+                "parse_ms": run.map(|run| as_ms(run.parse_time)),
+                "part1": part(part1),
+                "part2": part(part2),
+                "total_ms": run.map(|run| as_ms(run.total_time())),
+                "memory": run.and_then(|run| run.memory).map(|memory| json!({
+                    "parse": usage_json(memory.parse),
+                    "part1": memory.part1.map(usage_json),
+                    "part2": memory.part2.map(usage_json),
+                    "total": usage_json(memory.total),
+                })),
+                "error": record.outcome.error(),
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&records).unwrap()
+}
+
+// Quote a field if it would otherwise break the row
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+// The memory columns are the whole run's, and only filled in when allocations were counted
+fn render_csv(records: &[Record]) -> String {
+    let mut csv = String::from(
+        "year,day,title,input,status,parse_ms,part1,part1_kind,part1_ms,part2,part2_kind,part2_ms,total_ms,error,allocations,allocated_bytes,peak_bytes\n",
+    );
+    for record in records {
+        let ms = |time: Option<Duration>| time.map(|time| as_ms(time).to_string());
+        let run = record.outcome.run();
+        let mut fields = vec![
+            record.year.to_string(),
+            record.day.to_string(),
+            record.title.to_string(),
+            record.input.clone(),
+            record.status().to_string(),
+            ms(run.map(|run| run.parse_time)).unwrap_or_default(),
+        ];
+        for part in record.parts() {
+            fields.extend(match part {
+                Some((solution, time)) => [
+                    answer(solution).unwrap_or_default(),
+                    solution.kind().to_string(),
+                    ms(time).unwrap_or_default(),
+                ],
+                None => Default::default(),
+            });
+        }
+        fields.push(ms(run.map(Run::total_time)).unwrap_or_default());
+        fields.push(record.outcome.error().unwrap_or_default());
+        let total = run.and_then(|run| run.memory).map(|memory| memory.total);
+        fields.extend(match total {
+            Some(total) => [total.allocations, total.bytes, total.peak].map(|n| n.to_string()),
+            None => Default::default(),
+        });
+        csv += &fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+        csv += "\n";
+    }
+    csv
+}
+
+// The same progress table as the README, so it can be regenerated from a run of every day.
+// A part is a star once it has an answer, and in progress if it's unsolved, failed or timed out.
+// A part left out with --part is a "-", since whether it's solved wasn't checked.
+fn render_markdown(records: &[Record]) -> String {
+    let mut markdown =
+        String::from("| Day | Part One    | Part Two    |\n| --- | ----------- | ----------- |\n");
+    for record in records {
+        let [part1, part2] = record.parts().map(|part| match part {
+            Some((solution, _)) if solution.is_solved() => ":star:",
+            // Parts that weren't selected are never timed
+            Some((Solution::Skipped, None)) => "-",
+            // A solver that ran and skipped the part itself, as there's no puzzle to solve
+            Some((Solution::Skipped, Some(_))) => "",
+            _ => ":hammer_and_wrench:",
+        });
+        markdown += &format!("| {:<3} | {:<11} | {:<11} |\n", record.day, part1, part2);
+    }
+    markdown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::solver::Memory;
+
+    fn usage(allocations: u64, bytes: u64, peak: u64) -> Usage {
+        Usage {
+            allocations,
+            bytes,
+            peak,
+        }
+    }
+
+    fn records() -> Vec<Record> {
+        let run = Run {
+            part1: Solution::from(142u32),
+            part2: Solution::Skipped,
+            parse_time: Duration::from_micros(500),
+            part1_time: Some(Duration::from_millis(2)),
+            part2_time: None,
+            memory: Some(Memory {
+                parse: usage(2, 100, 100),
+                part1: Some(usage(3, 300, 200)),
+                part2: None,
+                total: usage(5, 400, 300),
+            }),
+        };
+        vec![
+            Record {
+                year: 2023,
+                day: 1,
+                title: "Trebuchet?!",
+                input: "input/2023/day01.txt".to_string(),
+                outcome: Outcome::Ran(run),
+            },
+            Record {
+                year: 2023,
+                day: 4,
+                title: "Scratchcards",
+                input: "input/2023/day04.txt".to_string(),
+                outcome: Outcome::Failed(
+                    Error::new("expected \"Card\", found x")
+                        .for_day(4)
+                        .at_line(1),
+                ),
+            },
+            Record {
+                year: 2023,
+                day: 21,
+                title: "Step Counter",
+                input: "input/2023/day21.txt".to_string(),
+                outcome: Outcome::TimedOut(Duration::from_millis(2500)),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_render_json() {
+        let json: Value = serde_json::from_str(&render(Format::Json, &records())).unwrap();
+        assert_eq!(json[0]["status"], "partial");
+        assert_eq!(
+            json[0]["part1"],
+            json!({"answer": "142", "kind": "u32", "ms": 2.0})
+        );
+        assert_eq!(
+            json[0]["part2"],
+            json!({"answer": null, "kind": "skipped", "ms": null})
+        );
+        assert_eq!(json[0]["total_ms"], 2.5);
+        assert_eq!(
+            json[0]["memory"]["part1"],
+            json!({"allocations": 3, "bytes": 300, "peak_bytes": 200})
+        );
+        assert_eq!(json[0]["memory"]["part2"], Value::Null);
+        assert_eq!(json[1]["memory"], Value::Null);
+        assert_eq!(json[1]["status"], "error");
+        assert_eq!(json[1]["part1"], Value::Null);
+        assert_eq!(
+            json[1]["error"],
+            "day 4, line 1: expected \"Card\", found x"
+        );
+        assert_eq!(json[2]["status"], "timed out");
+        assert_eq!(json[2]["error"], "timed out after 2.5s");
+    }
+
+    #[test]
+    fn test_render_csv() {
+        let csv = render(Format::Csv, &records());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[1],
+            "2023,1,Trebuchet?!,input/2023/day01.txt,partial,0.5,142,u32,2,,skipped,,2.5,,5,400,300"
+        );
+        assert_eq!(
+            lines[2],
+            r#"2023,4,Scratchcards,input/2023/day04.txt,error,,,,,,,,,"day 4, line 1: expected ""Card"", found x",,,"#
+        );
+        assert_eq!(
+            lines[3],
+            "2023,21,Step Counter,input/2023/day21.txt,timed out,,,,,,,,,timed out after 2.5s,,,"
+        );
+    }
+
+    #[test]
+    fn test_render_markdown() {
+        let markdown = render(Format::Markdown, &records());
+        assert_eq!(
+            markdown.lines().skip(2).collect::<Vec<_>>(),
+            [
+                "| 1   | :star:      | -           |",
+                "| 4   | :hammer_and_wrench: | :hammer_and_wrench: |",
+                "| 21  | :hammer_and_wrench: | :hammer_and_wrench: |"
+            ]
+        );
+
+        let mut records = records();
+        if let Outcome::Ran(run) = &mut records[0].outcome {
+            run.part2_time = Some(Duration::from_micros(1));
+        }
+        assert_eq!(
+            render(Format::Markdown, &records[..1]).lines().nth(2),
+            Some("| 1   | :star:      |             |")
+        );
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
@@ -0,0 +1,141 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../../templates/day.rs.tmpl");
+
+// The source of a new day from templates/day.rs.tmpl
+pub fn render(year: u16, day: u8, title: &str) -> String {
+    TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DD}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+        .replace(
+            "{{TITLE}}",
+            &title.replace('\\', "\\\\").replace('"', "\\\""),
+        )
+}
+
+// Create a new day under `root`, the top of the repository: its module in src/days/yYYYY,
+// declared in the year's mod.rs (and the year in src/days/mod.rs, for a new year), and an
+// empty input file. Returns the files created or changed.
+// Refuses if the day's module already exists, and leaves an existing input alone.
+pub fn new_day(root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<PathBuf>, String> {
+    let year_dir = root.join(format!("src/days/y{}", year));
+    let module = year_dir.join(format!("day{:02}.rs", day));
+    if module.exists() {
+        return Err(format!(
+            "{} already exists, so it was left alone",
+            module.display()
+        ));
+    }
+
+    let mut changed = Vec::new();
+    let year_mod = year_dir.join("mod.rs");
+    if !year_mod.exists() {
+        create_dir(&year_dir)?;
+        write(&year_mod, "")?;
+        let days_mod = root.join("src/days/mod.rs");
+        if declare(&days_mod, &format!("pub mod y{};", year))? {
+            changed.push(days_mod);
+        }
+    }
+    write(&module, &render(year, day, title))?;
+    changed.push(module);
+    if declare(&year_mod, &format!("pub mod day{:02};", day))? {
+        changed.push(year_mod);
+    }
+
+    let input = root.join(format!("input/{}/day{:02}.txt", year, day));
+    if !input.exists() {
+        create_dir(input.parent().unwrap())?;
+        write(&input, "")?;
+        changed.push(input);
+    }
+    Ok(changed)
+}
+
+// Add a `pub mod` line to a mod.rs, keeping the declarations sorted.
+// Returns whether it was added, rather than already there.
+fn declare(path: &Path, declaration: &str) -> Result<bool, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    let mut lines: Vec<&str> = contents.lines().collect();
+    if lines.contains(&declaration) {
+        return Ok(false);
+    }
+    // After the last declaration that sorts before it, or after any leading comments
+    let position = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod ") && *line < declaration)
+        .or_else(|| lines.iter().rposition(|line| line.starts_with("//")))
+        .map_or(0, |i| i + 1);
+    lines.insert(position, declaration);
+    write(path, &(lines.join("\n") + "\n"))?;
+    Ok(true)
+}
+
+fn create_dir(path: &Path) -> Result<(), String> {
+    fs::create_dir_all(path).map_err(|err| format!("could not create {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("could not write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let source = render(2023, 5, "If You \"Give\" A Seed");
+        assert!(source.contains("pub struct Day05;"));
+        assert!(source.contains("        2023\n"));
+        assert!(source.contains("        5\n"));
+        assert!(source.contains(r#""If You \"Give\" A Seed""#));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/days/y2023")).unwrap();
+        fs::write(root.join("src/days/mod.rs"), "// Years\npub mod y2023;\n").unwrap();
+        fs::write(
+            root.join("src/days/y2023/mod.rs"),
+            "pub mod day01;\npub mod day07;\n",
+        )
+        .unwrap();
+
+        let changed = new_day(&root, 2023, 5, "Day 5").unwrap();
+        assert_eq!(changed.len(), 3);
+        // Already declared, eg after deleting the module to start over
+        fs::remove_file(root.join("src/days/y2023/day05.rs")).unwrap();
+        let changed = new_day(&root, 2023, 5, "Day 5").unwrap();
+        assert_eq!(changed, [root.join("src/days/y2023/day05.rs")]);
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/y2023/mod.rs")).unwrap(),
+            "pub mod day01;\npub mod day05;\npub mod day07;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("input/2023/day05.txt")).unwrap(),
+            ""
+        );
+        assert!(new_day(&root, 2023, 5, "Day 5")
+            .unwrap_err()
+            .ends_with("already exists, so it was left alone"));
+
+        // A new year gets its own module
+        new_day(&root, 2015, 1, "Day 1").unwrap();
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/mod.rs")).unwrap(),
+            "// Years\npub mod y2015;\npub mod y2023;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/days/y2015/mod.rs")).unwrap(),
+            "pub mod day01;\n"
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
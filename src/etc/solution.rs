@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter, Result};
 use Solution::*;
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Solution {
     I8(i8),
     I16(i16),
@@ -16,6 +16,38 @@ pub enum Solution {
     U128(u128),
     Usize(usize),
     Str(String),
+    // The part hasn't been solved yet, eg a stubbed day
+    Unsolved,
+    // The part was deliberately not run, or there's no puzzle for it (day 25 only has one)
+    Skipped,
+}
+
+impl Solution {
+    // Whether this holds an actual answer, so a real answer of 0 still counts as solved
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Unsolved | Skipped)
+    }
+
+    // The name of the variant, eg "u64", "str" or "unsolved", for reports
+    pub fn kind(&self) -> &'static str {
+        match self {
+            I8(_) => "i8",
+            I16(_) => "i16",
+            I32(_) => "i32",
+            I64(_) => "i64",
+            I128(_) => "i128",
+            Isize(_) => "isize",
+            U8(_) => "u8",
+            U16(_) => "u16",
+            U32(_) => "u32",
+            U64(_) => "u64",
+            U128(_) => "u128",
+            Usize(_) => "usize",
+            Str(_) => "str",
+            Unsolved => "unsolved",
+            Skipped => "skipped",
+        }
+    }
 }
 
 impl Display for Solution {
@@ -34,6 +66,8 @@ impl Display for Solution {
             U128(x) => x.fmt(f),
             Usize(x) => x.fmt(f),
             Str(x) => x.fmt(f),
+            Unsolved => "unsolved".fmt(f),
+            Skipped => "skipped".fmt(f),
         }
     }
 }
@@ -45,7 +79,7 @@ macro_rules! impl_from {
                 Self::$kind_(sol)
             }
         }
-    }
+    };
 }
 
 impl_from!(i8, I8);
@@ -67,3 +101,20 @@ impl From<&str> for Solution {
         Self::Str(sol.to_owned())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_solved() {
+        assert!(Solution::from(0).is_solved());
+        assert!(Solution::from("").is_solved());
+        assert!(!Unsolved.is_solved());
+        assert!(!Skipped.is_solved());
+        assert_eq!(Unsolved.to_string(), "unsolved");
+        assert_eq!(Skipped.to_string(), "skipped");
+        assert_eq!(Solution::from(0u64).kind(), "u64");
+        assert_eq!(Unsolved.kind(), "unsolved");
+    }
+}
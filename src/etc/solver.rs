@@ -0,0 +1,393 @@
+use crate::etc::alloc::{measure, Usage};
+use crate::etc::error::Result;
+use crate::etc::solution::Solution;
+use itertools::Itertools;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+pub use inventory;
+
+// A Puzzle is one day's solution, split into parsing and the two parts so each step can be run
+// and timed on its own. Parsing happens once and both parts share the result, which may borrow
+// from the raw input.
+// Each day module implements this for a unit struct and registers it with `register!`,
+// so the runner never needs a hand-maintained table of days. A day whose puzzle changes a
+// number between the examples and the real input, eg how many steps to take, keeps it in a
+// field instead, so its examples can be solved with another value.
+pub trait Puzzle: Sync {
+    type Input<'a>;
+
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>>;
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Solution>;
+
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Solution>;
+
+    // Whether a part has a real solution yet, so stubbed days can be told apart without running them
+    fn is_implemented(&self, _part: Part) -> bool {
+        true
+    }
+
+    // The examples from the puzzle description, so a solver can be checked before it's run on
+    // the real input
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+}
+
+// An example input with the answers the puzzle gives for it, written the way Solution prints.
+// A part without an answer isn't checked, eg when the puzzle has a separate example for each part.
+// `solver` replaces the day's own solver for the example, eg one taking 6 steps instead of 64.
+#[derive(Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    pub solver: Option<&'static dyn Solver>,
+}
+
+impl Example {
+    pub const fn new(
+        input: &'static str,
+        part1: Option<&'static str>,
+        part2: Option<&'static str>,
+    ) -> Self {
+        Example {
+            input,
+            part1,
+            part2,
+            solver: None,
+        }
+    }
+
+    pub const fn with_solver(self, solver: &'static dyn Solver) -> Self {
+        Example {
+            solver: Some(solver),
+            ..self
+        }
+    }
+
+    // Run the example with its own solver, if it has one, or else with the day's
+    pub fn run(&self, solver: &dyn Solver, part: Option<Part>) -> Result<Run> {
+        self.solver.unwrap_or(solver).run(self.input, part)
+    }
+
+    pub fn answer(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+impl std::fmt::Debug for Example {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Example")
+            .field("input", &self.input)
+            .field("part1", &self.part1)
+            .field("part2", &self.part2)
+            .field("solver", &self.solver.map(|solver| solver.name()))
+            .finish()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+// The answers and timings from one run of a solver.
+// A part that wasn't selected is Skipped and has no time.
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub part1: Solution,
+    pub part2: Solution,
+    pub parse_time: Duration,
+    pub part1_time: Option<Duration>,
+    pub part2_time: Option<Duration>,
+    // Only when allocations are being counted
+    pub memory: Option<Memory>,
+}
+
+// What each step of a run allocated, and the run as a whole.
+// Like their times, parts that weren't selected have none.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Memory {
+    pub parse: Usage,
+    pub part1: Option<Usage>,
+    pub part2: Option<Usage>,
+    pub total: Usage,
+}
+
+impl Run {
+    pub fn total_time(&self) -> Duration {
+        self.parse_time + self.part1_time.unwrap_or_default() + self.part2_time.unwrap_or_default()
+    }
+}
+
+// Solves one part of an input that was already parsed, see `Solver::with_parsed`
+pub type SolvePart<'a> = dyn Fn(Part) -> Result<Solution> + 'a;
+
+// A Solver is a puzzle that the runner knows how to execute, with the input type erased
+// so that every day can live in the same registry.
+pub trait Solver: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    // A short unique identifier for the solver, eg "2023/day01"
+    fn name(&self) -> String {
+        format!("{}/day{:02}", self.year(), self.day())
+    }
+
+    // Where the runner looks for the puzzle input by default, eg "input/2023/day01.txt"
+    fn input_path(&self) -> PathBuf {
+        input_path(self.year(), self.day())
+    }
+
+    fn is_implemented(&self, part: Part) -> bool;
+
+    fn examples(&self) -> &'static [Example];
+
+    // Parse the input and solve the selected part, or both parts if none is given,
+    // tagging any error with the day it came from
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Run>;
+
+    // Parse the input once, then give `solve` a way to solve either part of it as often as it
+    // likes, eg to time the parts without parsing each time. Errors are tagged like `run`'s.
+    fn with_parsed(&self, input: &str, solve: &mut dyn FnMut(&SolvePart)) -> Result<()>;
+}
+
+impl<P: Puzzle> Solver for P {
+    fn year(&self) -> u16 {
+        Puzzle::year(self)
+    }
+
+    fn day(&self) -> u8 {
+        Puzzle::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Puzzle::title(self)
+    }
+
+    fn is_implemented(&self, part: Part) -> bool {
+        Puzzle::is_implemented(self, part)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        Puzzle::examples(self)
+    }
+
+    fn run(&self, input: &str, part: Option<Part>) -> Result<Run> {
+        let run = || -> Result<Run> {
+            let time = Instant::now();
+            let (parsed, parse_memory) = measure(|| self.parse(input));
+            let parsed = parsed?;
+            let parse_time = time.elapsed();
+            let (part1, part1_time, part1_memory) =
+                run_part(part, Part::One, || self.part1(&parsed))?;
+            let (part2, part2_time, part2_memory) =
+                run_part(part, Part::Two, || self.part2(&parsed))?;
+            Ok(Run {
+                part1,
+                part2,
+                parse_time,
+                part1_time,
+                part2_time,
+                memory: parse_memory.map(|parse| Memory {
+                    parse,
+                    part1: part1_memory,
+                    part2: part2_memory,
+                    total: Usage::default(),
+                }),
+            })
+        };
+        let (run, total) = measure(run);
+        let mut run = run.map_err(|err| err.for_day(Puzzle::day(self)))?;
+        if let (Some(memory), Some(total)) = (&mut run.memory, total) {
+            memory.total = total;
+        }
+        Ok(run)
+    }
+
+    fn with_parsed(&self, input: &str, solve: &mut dyn FnMut(&SolvePart)) -> Result<()> {
+        let day = Puzzle::day(self);
+        let parsed = self.parse(input).map_err(|err| err.for_day(day))?;
+        solve(&|part| {
+            match part {
+                Part::One => self.part1(&parsed),
+                Part::Two => self.part2(&parsed),
+            }
+            .map_err(|err| err.for_day(day))
+        });
+        Ok(())
+    }
+}
+
+fn run_part(
+    selected: Option<Part>,
+    part: Part,
+    solve: impl FnOnce() -> Result<Solution>,
+) -> Result<(Solution, Option<Duration>, Option<Usage>)> {
+    if selected.is_some_and(|selected| selected != part) {
+        return Ok((Solution::Skipped, None, None));
+    }
+    let time = Instant::now();
+    let (solution, memory) = measure(solve);
+    Ok((solution?, Some(time.elapsed()), memory))
+}
+
+// Wrapper collected by `inventory`, since it can only gather a single concrete type.
+pub struct Registration(pub &'static dyn Solver);
+
+inventory::collect!(Registration);
+
+// Add a solver to the registry. The argument must be a constant expression, usually a unit struct.
+#[macro_export]
+macro_rules! register {
+    ($solver:expr) => {
+        $crate::etc::solver::inventory::submit! {
+            $crate::etc::solver::Registration(&$solver)
+        }
+    };
+}
+
+// All registered solvers, sorted by year and then by day.
+pub fn solvers() -> Vec<&'static dyn Solver> {
+    inventory::iter::<Registration>
+        .into_iter()
+        .map(|registration| registration.0)
+        .sorted_by_key(|solver| (solver.year(), solver.day()))
+        .collect()
+}
+
+// Every year with at least one registered solver, oldest first.
+pub fn years() -> Vec<u16> {
+    solvers()
+        .iter()
+        .map(|solver| solver.year())
+        .dedup()
+        .collect()
+}
+
+// Where the input for a day is kept, whether or not it has a solver yet
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{}/day{:02}.txt", year, day))
+}
+
+// An optional smaller input kept next to the real one, eg an example to try while working on a day
+pub fn example_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("input/{}/day{:02}.example.txt", year, day))
+}
+
+// Where a day's solver is, from the top of the repository
+pub fn source_path(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("src/days/y{}/day{:02}.rs", year, day))
+}
+
+pub fn find_solver(year: u16, day: u8) -> Option<&'static dyn Solver> {
+    solvers()
+        .into_iter()
+        .find(|solver| solver.year() == year && solver.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solvers_are_unique_and_sorted() {
+        let keys = solvers()
+            .iter()
+            .map(|solver| (solver.year(), solver.day()))
+            .collect::<Vec<_>>();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_find_solver() {
+        let solver = find_solver(2023, 1).unwrap();
+        assert_eq!(solver.name(), "2023/day01");
+        assert_eq!(solver.title(), "Trebuchet?!");
+        assert_eq!(solver.input_path(), PathBuf::from("input/2023/day01.txt"));
+        assert!(find_solver(2023, 26).is_none());
+        assert!(find_solver(2014, 1).is_none());
+        assert!(years().contains(&2023));
+    }
+
+    #[test]
+    fn test_run() {
+        let solver = find_solver(2023, 1).unwrap();
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        let run = solver.run(input, None).unwrap();
+        assert_eq!(run.part1, Solution::from(142u32));
+        assert!(run.part1_time.is_some() && run.part2_time.is_some());
+
+        let run = solver.run(input, Some(Part::One)).unwrap();
+        assert_eq!(run.part1, Solution::from(142u32));
+        assert_eq!(run.part2, Solution::Skipped);
+        assert_eq!(run.part2_time, None);
+    }
+
+    // Every example a day declares gives the answers it claims to
+    #[test]
+    fn test_examples() {
+        for solver in solvers() {
+            for example in solver.examples() {
+                for part in [Part::One, Part::Two] {
+                    let Some(answer) = example.answer(part) else {
+                        continue;
+                    };
+                    let run = example.run(solver, Some(part)).unwrap();
+                    let solution = if part == Part::One {
+                        run.part1
+                    } else {
+                        run.part2
+                    };
+                    assert_eq!(solution.to_string(), answer, "{} {:?}", solver.name(), part);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_run_error() {
+        let solver = find_solver(2023, 1).unwrap();
+        let err = solver.run("1abc2\nnodigits", None).unwrap_err();
+        assert_eq!(err.day, Some(1));
+        assert_eq!(err.line, Some(2));
+    }
+
+    #[test]
+    fn test_with_parsed() {
+        let solver = find_solver(2023, 6).unwrap();
+        let mut solutions = Vec::new();
+        let input = solver.examples()[0].input;
+        solver
+            .with_parsed(input, &mut |solve| {
+                solutions.push(solve(Part::Two).unwrap());
+                solutions.push(solve(Part::One).unwrap());
+            })
+            .unwrap();
+        assert_eq!(
+            solutions,
+            [Solution::from(71503u64), Solution::from(288u64)]
+        );
+
+        let solver = find_solver(2023, 1).unwrap();
+        let parsed = solver.with_parsed("1abc2\nnodigits", &mut |solve| {
+            assert_eq!(solve(Part::One).unwrap_err().day, Some(1));
+        });
+        assert!(parsed.is_ok());
+    }
+}
@@ -0,0 +1,400 @@
+use crate::etc::client::Client;
+use crate::etc::solver::Part;
+use chrono::{DateTime, Utc};
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fs::{read_to_string, write};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+// What the site said about a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Feedback {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without saying which way
+    Wrong,
+    // Submitted too soon after the last answer, so not checked. The site says how long is left.
+    Wait(Option<Duration>),
+    // The part was already solved, or part 2 was submitted before part 1
+    WrongLevel,
+    // A page that isn't any of the above
+    Unknown,
+}
+
+impl Feedback {
+    pub fn parse(page: &str) -> Feedback {
+        lazy_static! {
+            static ref WAIT: Regex =
+                Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+        }
+        if page.contains("That's the right answer") {
+            Feedback::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Feedback::TooHigh
+            } else if page.contains("your answer is too low") {
+                Feedback::TooLow
+            } else {
+                Feedback::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Feedback::Wait(WAIT.captures(page).map(|captures| {
+                let number = |i| captures.get(i).map_or(0, |m| m.as_str().parse().unwrap());
+                Duration::from_secs(number(1) * 60 + number(2))
+            }))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Feedback::WrongLevel
+        } else {
+            Feedback::Unknown
+        }
+    }
+
+    // How the feedback is kept in the history file
+    fn tag(&self) -> &'static str {
+        match self {
+            Feedback::Correct => "correct",
+            Feedback::TooHigh => "too high",
+            Feedback::TooLow => "too low",
+            Feedback::Wrong => "wrong",
+            Feedback::Wait(_) => "wait",
+            Feedback::WrongLevel => "wrong level",
+            Feedback::Unknown => "unknown",
+        }
+    }
+
+    fn from_tag(tag: &str) -> Option<Feedback> {
+        Some(match tag {
+            "correct" => Feedback::Correct,
+            "too high" => Feedback::TooHigh,
+            "too low" => Feedback::TooLow,
+            "wrong" => Feedback::Wrong,
+            "wait" => Feedback::Wait(None),
+            "wrong level" => Feedback::WrongLevel,
+            "unknown" => Feedback::Unknown,
+            _ => return None,
+        })
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Feedback::TooHigh | Feedback::TooLow | Feedback::Wrong)
+    }
+}
+
+impl std::fmt::Display for Feedback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Feedback::Correct => write!(f, "That's the right answer!"),
+            Feedback::TooHigh => write!(f, "That's not the right answer, it's too high"),
+            Feedback::TooLow => write!(f, "That's not the right answer, it's too low"),
+            Feedback::Wrong => write!(f, "That's not the right answer"),
+            Feedback::Wait(Some(left)) => write!(
+                f,
+                "Answered too recently, wait {}s before trying again",
+                left.as_secs()
+            ),
+            Feedback::Wait(None) => write!(f, "Answered too recently, wait before trying again"),
+            Feedback::WrongLevel => write!(
+                f,
+                "Not the right part, it's either already solved or part 1 isn't yet"
+            ),
+            Feedback::Unknown => write!(f, "The site's response wasn't understood"),
+        }
+    }
+}
+
+// One answer that was sent, and what came back
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub time: DateTime<Utc>,
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub feedback: Feedback,
+}
+
+// Every answer submitted for a year, so that known wrong answers aren't sent again.
+// Kept as tab-separated lines of "<time>\t<day>\t<part>\t<answer>\t<feedback>".
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct History {
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    // Kept with the inputs, since the answers depend on them
+    pub fn path(year: u16) -> PathBuf {
+        PathBuf::from(format!("input/{}/submissions.tsv", year))
+    }
+
+    // An empty history if nothing has been submitted yet
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        write(path, self.to_string())
+    }
+
+    pub fn record(&mut self, attempt: Attempt) {
+        self.attempts.push(attempt);
+    }
+
+    fn attempts(&self, day: u8, part: Part) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |attempt| attempt.day == day && attempt.part == part)
+    }
+
+    // The range the answer must be in, from past answers that were too low or too high:
+    // above the highest answer that was too low, and below the lowest one that was too high
+    pub fn bounds(&self, day: u8, part: Part) -> (Option<i128>, Option<i128>) {
+        let answers = |feedback: Feedback| {
+            self.attempts(day, part)
+                .filter(move |attempt| attempt.feedback == feedback)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        (
+            answers(Feedback::TooLow).max(),
+            answers(Feedback::TooHigh).min(),
+        )
+    }
+
+    // Why the answer shouldn't be sent, if the history already says how it would go
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Result<(), String> {
+        if let Some(solved) = self
+            .attempts(day, part)
+            .find(|attempt| attempt.feedback == Feedback::Correct)
+        {
+            return Err(format!(
+                "this part is already solved, the answer was {}",
+                solved.answer
+            ));
+        }
+        if let Some(wrong) = self
+            .attempts(day, part)
+            .find(|attempt| attempt.answer == answer && attempt.feedback.is_wrong())
+        {
+            return Err(format!(
+                "{} was already submitted at {} and was wrong ({})",
+                answer,
+                wrong.time,
+                wrong.feedback.tag()
+            ));
+        }
+        if let Ok(number) = answer.parse::<i128>() {
+            match self.bounds(day, part) {
+                (Some(low), _) if number <= low => {
+                    return Err(format!(
+                        "{} is not above {}, which was already too low",
+                        answer, low
+                    ))
+                }
+                (_, Some(high)) if number >= high => {
+                    return Err(format!(
+                        "{} is not below {}, which was already too high",
+                        answer, high
+                    ))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for History {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let mut history = History::default();
+        for (i, line) in s.lines().enumerate().filter(|(_, line)| !line.is_empty()) {
+            let invalid = || {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "line {}: expected \"<time>\\t<day>\\t<part>\\t<answer>\\t<feedback>\"",
+                        i + 1
+                    ),
+                )
+            };
+            let [time, day, part, answer, feedback] = line.split('\t').collect::<Vec<_>>()[..]
+            else {
+                return Err(invalid());
+            };
+            history.record(Attempt {
+                time: time.parse().map_err(|_| invalid())?,
+                day: day.parse().map_err(|_| invalid())?,
+                part: match part {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return Err(invalid()),
+                },
+                answer: answer.to_string(),
+                feedback: Feedback::from_tag(feedback).ok_or_else(invalid)?,
+            });
+        }
+        Ok(history)
+    }
+}
+
+impl std::fmt::Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for attempt in &self.attempts {
+            writeln!(
+                f,
+                "{}\t{}\t{}\t{}\t{}",
+                attempt.time.to_rfc3339(),
+                attempt.day,
+                if attempt.part == Part::One { 1 } else { 2 },
+                attempt.answer,
+                attempt.feedback.tag()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+// Send an answer, unless the history says it's wrong, and record what the site said
+pub fn submit_answer(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<Feedback, String> {
+    history.check(day, part, answer)?;
+    let level = if part == Part::One { "1" } else { "2" };
+    let page = client.post(
+        &format!("/{}/day/{}/answer", year, day),
+        &[("level", level), ("answer", answer)],
+    )?;
+
+    let feedback = Feedback::parse(&page);
+    history.record(Attempt {
+        time: Utc::now(),
+        day,
+        part,
+        answer: answer.to_string(),
+        feedback: feedback.clone(),
+    });
+    Ok(feedback)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::etc::client::stub;
+
+    #[test]
+    fn test_parse_feedback() {
+        let page = |text: &str| format!("<main><article><p>{}</p></article></main>", text);
+        assert_eq!(
+            Feedback::parse(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Feedback::Correct
+        );
+        assert_eq!(
+            Feedback::parse(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Feedback::TooHigh
+        );
+        assert_eq!(
+            Feedback::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Feedback::TooLow
+        );
+        assert_eq!(
+            Feedback::parse(&page("That's not the right answer. If you're stuck...")),
+            Feedback::Wrong
+        );
+        assert_eq!(
+            Feedback::parse(&page(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Feedback::Wait(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Feedback::parse(&page(
+                "You don't seem to be solving the right level. Did you already complete it?"
+            )),
+            Feedback::WrongLevel
+        );
+        assert_eq!(Feedback::parse("<html></html>"), Feedback::Unknown);
+    }
+
+    #[test]
+    fn test_history() {
+        let attempt = |part, answer: &str, feedback| Attempt {
+            time: "2023-12-05T05:10:00Z".parse().unwrap(),
+            day: 5,
+            part,
+            answer: answer.to_string(),
+            feedback,
+        };
+        let mut history = History::default();
+        history.record(attempt(Part::One, "100", Feedback::TooLow));
+        history.record(attempt(Part::One, "500", Feedback::TooHigh));
+        history.record(attempt(Part::One, "300", Feedback::TooHigh));
+        history.record(attempt(Part::One, "250", Feedback::Wait(None)));
+        history.record(attempt(Part::Two, "x", Feedback::Wrong));
+
+        assert_eq!(history.bounds(5, Part::One), (Some(100), Some(300)));
+        assert_eq!(history.check(5, Part::One, "250"), Ok(()));
+        assert_eq!(
+            history.check(5, Part::One, "400").unwrap_err(),
+            "400 is not below 300, which was already too high"
+        );
+        assert!(history.check(5, Part::One, "100").is_err());
+        assert_eq!(
+            history.check(5, Part::One, "50").unwrap_err(),
+            "50 is not above 100, which was already too low"
+        );
+        assert!(history.check(5, Part::Two, "x").is_err());
+        assert_eq!(history.check(5, Part::Two, "y"), Ok(()));
+        assert_eq!(history.check(6, Part::One, "400"), Ok(()));
+
+        history.record(attempt(Part::Two, "y", Feedback::Correct));
+        assert!(history
+            .check(5, Part::Two, "z")
+            .unwrap_err()
+            .contains("already solved"));
+
+        let parsed: History = history.to_string().parse().unwrap();
+        assert_eq!(parsed, history);
+        assert!("2023-12-05T05:10:00Z\t5\t3\t1\twrong"
+            .parse::<History>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (config, requests) = stub::server(
+            200,
+            "<p>That's not the right answer; your answer is too high.</p>",
+        );
+        let client = Client::new(&config).unwrap();
+        let mut history = History::default();
+
+        let feedback = submit_answer(&client, &mut history, 2023, 5, Part::Two, "1234");
+        assert_eq!(feedback, Ok(Feedback::TooHigh));
+        let request = requests.recv().unwrap();
+        assert_eq!(request.url, "/2023/day/5/answer");
+        assert_eq!(request.body, "level=2&answer=1234");
+        assert_eq!(history.bounds(5, Part::Two), (None, Some(1234)));
+
+        // Known to be wrong, so never sent
+        let feedback = submit_answer(&client, &mut history, 2023, 5, Part::Two, "2000");
+        assert!(feedback.unwrap_err().contains("2000 is not below 1234"));
+        assert_eq!(requests.try_iter().count(), 0);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::etc::bench::percent_change;
+use crate::etc::solution::Solution;
+use crate::etc::solver::Run;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+// When a file was last modified and its size, or None if it doesn't exist
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+// Notices changes to some files by checking their modification times and sizes every
+// `interval`, so it works anywhere without any file system notifications.
+// A file being created or removed counts as a change.
+pub struct Watcher {
+    files: Vec<(PathBuf, Stamp)>,
+    interval: Duration,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>, interval: Duration) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let stamp = stamp(&path);
+                (path, stamp)
+            })
+            .collect();
+        Watcher { files, interval }
+    }
+
+    // The files that changed since they were last checked
+    pub fn changed(&mut self) -> Vec<PathBuf> {
+        let mut changed = Vec::new();
+        for (path, last) in &mut self.files {
+            let now = stamp(path);
+            if now != *last {
+                *last = now;
+                changed.push(path.clone());
+            }
+        }
+        changed
+    }
+
+    // Block until some files change, and return them.
+    // Editors often save a file in more than one write, so it waits for them to settle first.
+    pub fn wait(&mut self) -> Vec<PathBuf> {
+        loop {
+            thread::sleep(self.interval);
+            let mut changed = self.changed();
+            if changed.is_empty() {
+                continue;
+            }
+            thread::sleep(self.interval);
+            for path in self.changed() {
+                if !changed.contains(&path) {
+                    changed.push(path);
+                }
+            }
+            return changed;
+        }
+    }
+}
+
+// Describe each step of a run like the runner does, along with how it changed from the
+// previous run: answers that changed show what they were, and times show the change in percent.
+pub fn compare(run: &Run, previous: Option<&Run>) -> Vec<String> {
+    let time = |time: Duration, old: Option<Duration>| match old
+        .and_then(|old| percent_change(old, time))
+    {
+        Some(change) => format!("{:.4} ms, {:+.1}%", as_ms(time), change),
+        None => format!("{:.4} ms", as_ms(time)),
+    };
+
+    let mut lines = vec![format!(
+        "Parse: {}",
+        time(run.parse_time, previous.map(|previous| previous.parse_time))
+    )];
+    let old_parts = previous.map(|p| [(&p.part1, p.part1_time), (&p.part2, p.part2_time)]);
+    let parts = [(&run.part1, run.part1_time), (&run.part2, run.part2_time)];
+    for (i, (solution, part_time)) in parts.into_iter().enumerate() {
+        let old = old_parts.map(|old_parts| old_parts[i]);
+        // A part skipped in either run has no answer to compare
+        let skipped = |solution: &Solution| *solution == Solution::Skipped;
+        let answer = match old {
+            Some((old, _)) if old != solution && !skipped(old) && !skipped(solution) => {
+                format!("{}, was {}", solution, old)
+            }
+            _ => solution.to_string(),
+        };
+        match part_time {
+            Some(part_time) => lines.push(format!(
+                "Part {}: {} ({})",
+                i + 1,
+                answer,
+                time(part_time, old.and_then(|(_, time)| time))
+            )),
+            None => lines.push(format!("Part {}: {}", i + 1, answer)),
+        }
+    }
+    lines.push(format!(
+        "Elapsed: {}",
+        time(run.total_time(), previous.map(Run::total_time))
+    ));
+    lines
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn test_watcher() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let (input, example) = (dir.join("day05.txt"), dir.join("day05.example.txt"));
+        fs::write(&input, "1 2 3").unwrap();
+
+        let mut watcher = Watcher::new(
+            vec![input.clone(), example.clone()],
+            Duration::from_millis(1),
+        );
+        assert!(watcher.changed().is_empty());
+
+        // Set the time too, since a quick rewrite can keep the same modification time
+        let file = fs::File::options().write(true).open(&input).unwrap();
+        file.set_modified(UNIX_EPOCH + Duration::from_secs(60))
+            .unwrap();
+        drop(file);
+        fs::write(&example, "1").unwrap();
+        assert_eq!(watcher.wait(), [input.clone(), example.clone()]);
+        assert!(watcher.changed().is_empty());
+
+        fs::remove_file(&example).unwrap();
+        assert_eq!(watcher.changed(), [example]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let ms = Duration::from_millis;
+        let run = |part1: u64, part2: Solution, part2_time| Run {
+            part1: Solution::from(part1),
+            part2,
+            parse_time: ms(1),
+            part1_time: Some(ms(3)),
+            part2_time,
+            memory: None,
+        };
+
+        let first = run(35, Solution::Unsolved, Some(ms(2)));
+        assert_eq!(
+            compare(&first, None),
+            [
+                "Parse: 1.0000 ms",
+                "Part 1: 35 (3.0000 ms)",
+                "Part 2: unsolved (2.0000 ms)",
+                "Elapsed: 6.0000 ms"
+            ]
+        );
+
+        let second = run(35, Solution::from(46u64), Some(ms(1)));
+        assert_eq!(
+            compare(&second, Some(&first)),
+            [
+                "Parse: 1.0000 ms, +0.0%",
+                "Part 1: 35 (3.0000 ms, +0.0%)",
+                "Part 2: 46, was unsolved (1.0000 ms, -50.0%)",
+                "Elapsed: 5.0000 ms, -16.7%"
+            ]
+        );
+
+        let skipped = run(35, Solution::Skipped, None);
+        assert_eq!(compare(&skipped, Some(&first))[2], "Part 2: skipped");
+        assert_eq!(
+            compare(&second, Some(&skipped))[2],
+            "Part 2: 46 (1.0000 ms)"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+// The solutions and everything needed to run them, as a library so that other tools and the
+// benches can use them too. The runner in main.rs is a thin command line over
+// etc::commands.
+//
+// The items re-exported here are the stable API: the solvers and the registry to find them,
+// and the types they share. The modules under etc are what the runner is built from, and
+// the days keep their own helpers public where they're worth reusing.
+pub mod days;
+pub mod etc;
+
+pub use etc::solver::{find_solver, input_path, solvers, years};
+pub use etc::{Error, Example, Part, Puzzle, Result, Run, Solution, Solver};
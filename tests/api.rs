@@ -0,0 +1,24 @@
+// The library as another crate sees it
+use aoc_2023_jmm::days::y2023::{day05, day20};
+use aoc_2023_jmm::{find_solver, solvers, Part, Solution};
+
+#[test]
+fn test_run_solver() {
+    assert!(solvers().len() >= 25);
+    let solver = find_solver(2023, 6).unwrap();
+    let example = solver.examples()[0];
+    let run = solver.run(example.input, Some(Part::One)).unwrap();
+    assert_eq!(run.part1, Solution::from(288u64));
+    assert_eq!(run.part2, Solution::Skipped);
+}
+
+#[test]
+fn test_day_helpers() {
+    let mut map = day05::Map::new();
+    map.add_range_shift(98, 50, 2);
+    assert_eq!(map.get_destination(99), 51);
+    assert_eq!(map.transform_range(97, 99), [(97, 98), (50, 51)]);
+
+    let system = day20::initialize_system(&["broadcaster -> a", "%a -> b", "&b -> a"]).unwrap();
+    assert!(day20::product_low_high_pulses(system) > 0);
+}